@@ -0,0 +1,25 @@
+use anchor_lang::solana_program::account_info::AccountInfo;
+use anchor_lang::solana_program::program_error::ProgramError;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_lang::{solana_program, Result};
+
+// Helpers shared by the `token` and `token_2022` CPI wrappers.
+
+// Reads the data returned by the last CPI, making sure it came from the
+// token program that was invoked.
+pub(crate) fn return_data(program_id: &Pubkey) -> Result<Vec<u8>> {
+    match solana_program::program::get_return_data() {
+        Some((key, data)) if key == *program_id => Ok(data),
+        Some(_) => Err(ProgramError::IncorrectProgramId.into()),
+        None => Err(ProgramError::InvalidInstructionData.into()),
+    }
+}
+
+// Multisig signers are taken from `ctx.remaining_accounts`, in the order the
+// multisig expects them. With no remaining accounts the authority signs alone.
+pub(crate) fn multisig_signers<'a>(remaining_accounts: &'a [AccountInfo]) -> Vec<&'a Pubkey> {
+    remaining_accounts
+        .iter()
+        .map(|account| account.key)
+        .collect()
+}
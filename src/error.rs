@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+#[cfg(any(feature = "token", feature = "metadata"))]
+use num_traits::FromPrimitive;
+
+/// Errors returned by the helpers of this crate.
+///
+/// The codes start at 9000 so they do not collide with the 6000-based codes of
+/// the program using this crate. A Token or Metaplex program error raised inside
+/// a CPI aborts the whole transaction, so helpers never see it; composite helpers
+/// log each step before invoking it instead, and the last step in the logs is the
+/// one that failed. The variants do not carry the step, so the logs are the only
+/// place it is reported. Off-chain, `from_token_error` and `from_metadata_error`
+/// decode the custom code of such a failure into the variants below.
+#[error_code(offset = 9000)]
+pub enum SplSimplifiedError {
+    // Token program errors.
+    #[msg("Token: lamport balance below rent-exempt threshold")]
+    TokenNotRentExempt,
+    #[msg("Token: insufficient funds")]
+    InsufficientFunds,
+    #[msg("Token: invalid mint")]
+    InvalidMint,
+    #[msg("Token: account not associated with this mint")]
+    TokenMintMismatch,
+    #[msg("Token: owner does not match")]
+    TokenOwnerMismatch,
+    #[msg("Token: this token's supply is fixed and new tokens cannot be minted")]
+    FixedSupply,
+    #[msg("Token: the account is already in use")]
+    AlreadyInUse,
+    #[msg("Token: invalid number of provided signers")]
+    InvalidNumberOfProvidedSigners,
+    #[msg("Token: invalid number of required signers")]
+    InvalidNumberOfRequiredSigners,
+    #[msg("Token: state is uninitialized")]
+    UninitializedState,
+    #[msg("Token: instruction does not support native tokens")]
+    NativeNotSupported,
+    #[msg("Token: non-native account can only be closed if its balance is zero")]
+    NonNativeHasBalance,
+    #[msg("Token: invalid instruction")]
+    InvalidTokenInstruction,
+    #[msg("Token: state is invalid for requested operation")]
+    InvalidTokenState,
+    #[msg("Token: operation overflowed")]
+    TokenOverflow,
+    #[msg("Token: account does not support specified authority type")]
+    AuthorityTypeNotSupported,
+    #[msg("Token: this mint cannot freeze accounts")]
+    MintCannotFreeze,
+    #[msg("Token: account is frozen")]
+    AccountFrozen,
+    #[msg("Token: the provided decimals differ from the mint decimals")]
+    MintDecimalsMismatch,
+    #[msg("Token: instruction does not support non-native tokens")]
+    NonNativeNotSupported,
+    #[msg("Token: the token program returned an unknown error")]
+    TokenProgramFailed,
+
+    // Token Metadata program errors.
+    #[msg("Metadata: account is already initialized")]
+    MetadataAlreadyInitialized,
+    #[msg("Metadata: account is uninitialized")]
+    MetadataUninitialized,
+    #[msg("Metadata: metadata account is not the PDA of the mint")]
+    InvalidMetadataKey,
+    #[msg("Metadata: edition account is not the PDA of the mint")]
+    InvalidEditionKey,
+    #[msg("Metadata: update authority does not match")]
+    UpdateAuthorityIncorrect,
+    #[msg("Metadata: update authority must sign")]
+    UpdateAuthorityIsNotSigner,
+    #[msg("Metadata: the mint authority must sign")]
+    NotMintAuthority,
+    #[msg("Metadata: mint authority does not match the mint")]
+    InvalidMintAuthority,
+    #[msg("Metadata: name too long")]
+    NameTooLong,
+    #[msg("Metadata: symbol too long")]
+    SymbolTooLong,
+    #[msg("Metadata: URI too long")]
+    UriTooLong,
+    #[msg("Metadata: mint does not match the metadata mint")]
+    MetadataMintMismatch,
+    #[msg("Metadata: editions must have exactly one token")]
+    EditionsMustHaveExactlyOneToken,
+    #[msg("Metadata: the maximum number of editions was already printed")]
+    MaxEditionsMintedAlready,
+    #[msg("Metadata: creators list too long")]
+    CreatorsTooLong,
+    #[msg("Metadata: creators must be at least one if set")]
+    CreatorsMustBeAtLeastOne,
+    #[msg("Metadata: seller fee basis points cannot be more than 10000")]
+    InvalidBasisPoints,
+    #[msg("Metadata: creator shares must add up to 100")]
+    ShareTotalMustBe100,
+    #[msg("Metadata: a creator other than the signer cannot be verified")]
+    CannotVerifyAnotherCreator,
+    #[msg("Metadata: incorrect account owner")]
+    MetadataIncorrectOwner,
+    #[msg("Metadata: data is immutable")]
+    DataIsImmutable,
+    #[msg("Metadata: duplicate creator address")]
+    DuplicateCreatorAddress,
+    #[msg("Metadata: collection not found on metadata")]
+    CollectionNotFound,
+    #[msg("Metadata: collection update authority is invalid")]
+    InvalidCollectionUpdateAuthority,
+    #[msg("Metadata: collection must be a unique master edition")]
+    CollectionMustBeAUniqueMasterEdition,
+    #[msg("Metadata: the metadata program returned an unknown error")]
+    MetadataProgramFailed,
+
+    // Errors of the simple layer.
+    #[msg("A required account was not provided")]
+    MissingAccount,
+    #[msg("Source and destination must be token accounts of the transferred mint")]
+    TransferMintMismatch,
+    #[msg("Metadata: uses are invalid for the use method")]
+    InvalidUses,
+    #[msg("Account is not the expected PDA")]
+    PdaMismatch,
+    #[msg("Metadata: the item is not a verified member of the collection")]
+    NotInCollection,
+    #[msg("Account is not the instructions sysvar")]
+    InvalidSysvarInstructions,
+    #[msg("Metadata: the asset is not a programmable NFT")]
+    NotProgrammable,
+    #[msg("Metadata: authorization rules do not match the rule set of the metadata")]
+    RuleSetMismatch,
+    #[msg("Mint is not the native mint of the token program")]
+    NotNativeMint,
+}
+
+impl SplSimplifiedError {
+    /// Decodes the custom error code of a failed Token or Token-2022 instruction,
+    /// e.g. from a transaction simulation or its status off-chain.
+    ///
+    /// Token-2022 shares the legacy codes; its extension specific codes map to
+    /// `TokenProgramFailed`.
+    #[cfg(feature = "token")]
+    pub fn from_token_error(code: u32) -> Self {
+        use spl_token::error::TokenError;
+
+        match TokenError::from_u32(code) {
+            Some(TokenError::NotRentExempt) => Self::TokenNotRentExempt,
+            Some(TokenError::InsufficientFunds) => Self::InsufficientFunds,
+            Some(TokenError::InvalidMint) => Self::InvalidMint,
+            Some(TokenError::MintMismatch) => Self::TokenMintMismatch,
+            Some(TokenError::OwnerMismatch) => Self::TokenOwnerMismatch,
+            Some(TokenError::FixedSupply) => Self::FixedSupply,
+            Some(TokenError::AlreadyInUse) => Self::AlreadyInUse,
+            Some(TokenError::InvalidNumberOfProvidedSigners) => {
+                Self::InvalidNumberOfProvidedSigners
+            }
+            Some(TokenError::InvalidNumberOfRequiredSigners) => {
+                Self::InvalidNumberOfRequiredSigners
+            }
+            Some(TokenError::UninitializedState) => Self::UninitializedState,
+            Some(TokenError::NativeNotSupported) => Self::NativeNotSupported,
+            Some(TokenError::NonNativeHasBalance) => Self::NonNativeHasBalance,
+            Some(TokenError::InvalidInstruction) => Self::InvalidTokenInstruction,
+            Some(TokenError::InvalidState) => Self::InvalidTokenState,
+            Some(TokenError::Overflow) => Self::TokenOverflow,
+            Some(TokenError::AuthorityTypeNotSupported) => Self::AuthorityTypeNotSupported,
+            Some(TokenError::MintCannotFreeze) => Self::MintCannotFreeze,
+            Some(TokenError::AccountFrozen) => Self::AccountFrozen,
+            Some(TokenError::MintDecimalsMismatch) => Self::MintDecimalsMismatch,
+            Some(TokenError::NonNativeNotSupported) => Self::NonNativeNotSupported,
+            None => Self::TokenProgramFailed,
+        }
+    }
+
+    /// Decodes the custom error code of a failed Token Metadata instruction, e.g.
+    /// from a transaction simulation or its status off-chain.
+    #[cfg(feature = "metadata")]
+    pub fn from_metadata_error(code: u32) -> Self {
+        use mpl_token_metadata::errors::MplTokenMetadataError as E;
+
+        match E::from_u32(code) {
+            Some(E::AlreadyInitialized) => Self::MetadataAlreadyInitialized,
+            Some(E::Uninitialized) => Self::MetadataUninitialized,
+            Some(E::InvalidMetadataKey) => Self::InvalidMetadataKey,
+            Some(E::InvalidEditionKey) => Self::InvalidEditionKey,
+            Some(E::UpdateAuthorityIncorrect) => Self::UpdateAuthorityIncorrect,
+            Some(E::UpdateAuthorityIsNotSigner) => Self::UpdateAuthorityIsNotSigner,
+            Some(E::NotMintAuthority) => Self::NotMintAuthority,
+            Some(E::InvalidMintAuthority) => Self::InvalidMintAuthority,
+            Some(E::NameTooLong) => Self::NameTooLong,
+            Some(E::SymbolTooLong) => Self::SymbolTooLong,
+            Some(E::UriTooLong) => Self::UriTooLong,
+            Some(E::MintMismatch) => Self::MetadataMintMismatch,
+            Some(E::EditionsMustHaveExactlyOneToken) => Self::EditionsMustHaveExactlyOneToken,
+            Some(E::MaxEditionsMintedAlready) => Self::MaxEditionsMintedAlready,
+            Some(E::CreatorsTooLong) => Self::CreatorsTooLong,
+            Some(E::CreatorsMustBeAtleastOne) => Self::CreatorsMustBeAtLeastOne,
+            Some(E::InvalidBasisPoints) => Self::InvalidBasisPoints,
+            Some(E::ShareTotalMustBe100) => Self::ShareTotalMustBe100,
+            Some(E::CannotVerifyAnotherCreator) => Self::CannotVerifyAnotherCreator,
+            Some(E::IncorrectOwner) => Self::MetadataIncorrectOwner,
+            Some(E::DataIsImmutable) => Self::DataIsImmutable,
+            Some(E::DuplicateCreatorAddress) => Self::DuplicateCreatorAddress,
+            Some(E::CollectionNotFound) => Self::CollectionNotFound,
+            Some(E::InvalidCollectionUpdateAuthority) => Self::InvalidCollectionUpdateAuthority,
+            Some(E::CollectionMustBeAUniqueMasterEdition) => {
+                Self::CollectionMustBeAUniqueMasterEdition
+            }
+            _ => Self::MetadataProgramFailed,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::pubkey::Pubkey;
+
+pub fn create(
+    payer: &Pubkey,
+    authority: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Vec<Instruction> {
+    vec![
+        spl_associated_token_account::instruction::create_associated_token_account(
+            payer,
+            authority,
+            mint,
+            token_program,
+        ),
+    ]
+}
+
+pub fn create_idempotent(
+    payer: &Pubkey,
+    authority: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Vec<Instruction> {
+    vec![
+        spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+            payer,
+            authority,
+            mint,
+            token_program,
+        ),
+    ]
+}
@@ -0,0 +1,905 @@
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program;
+use anchor_lang::Result;
+use mpl_token_metadata::instructions as mpl;
+use mpl_token_metadata::types::{
+    BurnArgs, CollectionDetails, CreateArgs, DataV2, DelegateArgs, LockArgs, MintArgs, PrintArgs,
+    RevokeArgs, TransferArgs, UnlockArgs, UpdateArgs, UseArgs, VerificationArgs,
+};
+
+use crate::metadata::{validate_data_v2, AUTH_RULES_PROGRAM_ID};
+
+pub fn approve_collection_authority(
+    collection_authority_record: &Pubkey,
+    new_collection_authority: &Pubkey,
+    update_authority: &Pubkey,
+    payer: &Pubkey,
+    metadata: &Pubkey,
+    mint: &Pubkey,
+) -> Vec<Instruction> {
+    vec![mpl::ApproveCollectionAuthority {
+        collection_authority_record: *collection_authority_record,
+        metadata: *metadata,
+        mint: *mint,
+        new_collection_authority: *new_collection_authority,
+        payer: *payer,
+        rent: None,
+        system_program: system_program::ID,
+        update_authority: *update_authority,
+    }
+    .instruction()]
+}
+
+pub fn bubblegum_set_collection_size(
+    metadata: &Pubkey,
+    update_authority: &Pubkey,
+    mint: &Pubkey,
+    bubblegum_signer: &Pubkey,
+    collection_authority_record: Option<Pubkey>,
+    size: u64,
+) -> Vec<Instruction> {
+    vec![mpl::BubblegumSetCollectionSize {
+        collection_metadata: *metadata,
+        collection_authority: *update_authority,
+        collection_mint: *mint,
+        bubblegum_signer: *bubblegum_signer,
+        collection_authority_record,
+    }
+    .instruction(mpl::BubblegumSetCollectionSizeInstructionArgs {
+        set_collection_size_args: mpl_token_metadata::types::SetCollectionSizeArgs { size },
+    })]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn burn_edition_nft(
+    metadata: &Pubkey,
+    owner: &Pubkey,
+    print_edition_mint: &Pubkey,
+    master_edition_mint: &Pubkey,
+    print_edition_token: &Pubkey,
+    master_edition_token: &Pubkey,
+    master_edition: &Pubkey,
+    print_edition: &Pubkey,
+    edition_marker: &Pubkey,
+    spl_token: &Pubkey,
+) -> Vec<Instruction> {
+    vec![mpl::BurnEditionNft {
+        edition_marker_account: *edition_marker,
+        master_edition_account: *master_edition,
+        master_edition_mint: *master_edition_mint,
+        master_edition_token_account: *master_edition_token,
+        metadata: *metadata,
+        owner: *owner,
+        print_edition_account: *print_edition,
+        print_edition_mint: *print_edition_mint,
+        print_edition_token_account: *print_edition_token,
+        spl_token_program: *spl_token,
+    }
+    .instruction()]
+}
+
+pub fn burn_nft(
+    metadata: &Pubkey,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    token: &Pubkey,
+    edition: &Pubkey,
+    spl_token: &Pubkey,
+    collection_metadata: Option<Pubkey>,
+) -> Vec<Instruction> {
+    vec![mpl::BurnNft {
+        collection_metadata,
+        master_edition_account: *edition,
+        metadata: *metadata,
+        mint: *mint,
+        owner: *owner,
+        spl_token_program: *spl_token,
+        token_account: *token,
+    }
+    .instruction()]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_metadata_accounts_v3(
+    metadata: &Pubkey,
+    mint: &Pubkey,
+    mint_authority: &Pubkey,
+    payer: &Pubkey,
+    update_authority: &Pubkey,
+    data: DataV2,
+    is_mutable: bool,
+    update_authority_is_signer: bool,
+    collection_details: Option<CollectionDetails>,
+) -> Result<Vec<Instruction>> {
+    validate_data_v2(&data)?;
+    Ok(vec![mpl::CreateMetadataAccountV3 {
+        metadata: *metadata,
+        mint: *mint,
+        mint_authority: *mint_authority,
+        payer: *payer,
+        rent: None,
+        system_program: system_program::ID,
+        update_authority: (*update_authority, update_authority_is_signer),
+    }
+    .instruction(mpl::CreateMetadataAccountV3InstructionArgs {
+        collection_details,
+        data,
+        is_mutable,
+    })])
+}
+
+pub fn update_metadata_accounts_v2(
+    metadata: &Pubkey,
+    update_authority: &Pubkey,
+    new_update_authority: Option<Pubkey>,
+    data: Option<DataV2>,
+    primary_sale_happened: Option<bool>,
+    is_mutable: Option<bool>,
+) -> Result<Vec<Instruction>> {
+    if let Some(data) = &data {
+        validate_data_v2(data)?;
+    }
+    Ok(vec![mpl::UpdateMetadataAccountV2 {
+        metadata: *metadata,
+        update_authority: *update_authority,
+    }
+    .instruction(mpl::UpdateMetadataAccountV2InstructionArgs {
+        new_update_authority,
+        data,
+        primary_sale_happened,
+        is_mutable,
+    })])
+}
+
+pub fn create_master_edition_v3(
+    edition: &Pubkey,
+    mint: &Pubkey,
+    update_authority: &Pubkey,
+    mint_authority: &Pubkey,
+    payer: &Pubkey,
+    metadata: &Pubkey,
+    max_supply: Option<u64>,
+) -> Vec<Instruction> {
+    vec![mpl::CreateMasterEditionV3 {
+        edition: *edition,
+        metadata: *metadata,
+        mint: *mint,
+        mint_authority: *mint_authority,
+        payer: *payer,
+        rent: None,
+        system_program: system_program::ID,
+        token_program: spl_token::ID,
+        update_authority: *update_authority,
+    }
+    .instruction(mpl::CreateMasterEditionV3InstructionArgs { max_supply })]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn mint_new_edition_from_master_edition_via_token(
+    new_metadata: &Pubkey,
+    new_edition: &Pubkey,
+    master_edition: &Pubkey,
+    new_mint: &Pubkey,
+    edition_mark_pda: &Pubkey,
+    new_mint_authority: &Pubkey,
+    payer: &Pubkey,
+    token_account_owner: &Pubkey,
+    token_account: &Pubkey,
+    new_metadata_update_authority: &Pubkey,
+    metadata: &Pubkey,
+    edition: u64,
+) -> Vec<Instruction> {
+    vec![mpl::MintNewEditionFromMasterEditionViaToken {
+        edition_mark_pda: *edition_mark_pda,
+        master_edition: *master_edition,
+        metadata: *metadata,
+        new_edition: *new_edition,
+        new_metadata: *new_metadata,
+        new_metadata_update_authority: *new_metadata_update_authority,
+        new_mint: *new_mint,
+        new_mint_authority: *new_mint_authority,
+        payer: *payer,
+        rent: None,
+        system_program: system_program::ID,
+        token_account: *token_account,
+        token_account_owner: *token_account_owner,
+        token_program: spl_token::ID,
+    }
+    .instruction(
+        mpl::MintNewEditionFromMasterEditionViaTokenInstructionArgs {
+            mint_new_edition_from_master_edition_via_token_args:
+                mpl_token_metadata::types::MintNewEditionFromMasterEditionViaTokenArgs { edition },
+        },
+    )]
+}
+
+pub fn revoke_collection_authority(
+    collection_authority_record: &Pubkey,
+    delegate_authority: &Pubkey,
+    revoke_authority: &Pubkey,
+    metadata: &Pubkey,
+    mint: &Pubkey,
+) -> Vec<Instruction> {
+    vec![mpl::RevokeCollectionAuthority {
+        collection_authority_record: *collection_authority_record,
+        delegate_authority: *delegate_authority,
+        metadata: *metadata,
+        mint: *mint,
+        revoke_authority: *revoke_authority,
+    }
+    .instruction()]
+}
+
+pub fn set_collection_size(
+    metadata: &Pubkey,
+    mint: &Pubkey,
+    update_authority: &Pubkey,
+    collection_authority_record: Option<Pubkey>,
+    size: u64,
+) -> Vec<Instruction> {
+    vec![mpl::SetCollectionSize {
+        collection_authority: *update_authority,
+        collection_authority_record,
+        collection_metadata: *metadata,
+        collection_mint: *mint,
+    }
+    .instruction(mpl::SetCollectionSizeInstructionArgs {
+        set_collection_size_args: mpl_token_metadata::types::SetCollectionSizeArgs { size },
+    })]
+}
+
+pub fn verify_collection(
+    payer: &Pubkey,
+    metadata: &Pubkey,
+    collection_authority: &Pubkey,
+    collection_mint: &Pubkey,
+    collection_metadata: &Pubkey,
+    collection_master_edition: &Pubkey,
+    collection_authority_record: Option<Pubkey>,
+) -> Vec<Instruction> {
+    vec![mpl::VerifyCollection {
+        collection: *collection_metadata,
+        collection_authority: *collection_authority,
+        collection_authority_record,
+        collection_master_edition_account: *collection_master_edition,
+        collection_mint: *collection_mint,
+        metadata: *metadata,
+        payer: *payer,
+    }
+    .instruction()]
+}
+
+pub fn verify_sized_collection_item(
+    payer: &Pubkey,
+    metadata: &Pubkey,
+    collection_authority: &Pubkey,
+    collection_mint: &Pubkey,
+    collection_metadata: &Pubkey,
+    collection_master_edition: &Pubkey,
+    collection_authority_record: Option<Pubkey>,
+) -> Vec<Instruction> {
+    vec![mpl::VerifySizedCollectionItem {
+        collection: *collection_metadata,
+        collection_authority: *collection_authority,
+        collection_authority_record,
+        collection_master_edition_account: *collection_master_edition,
+        collection_mint: *collection_mint,
+        metadata: *metadata,
+        payer: *payer,
+    }
+    .instruction()]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn set_and_verify_collection(
+    metadata: &Pubkey,
+    collection_authority: &Pubkey,
+    payer: &Pubkey,
+    update_authority: &Pubkey,
+    collection_mint: &Pubkey,
+    collection_metadata: &Pubkey,
+    collection_master_edition: &Pubkey,
+    collection_authority_record: Option<Pubkey>,
+) -> Vec<Instruction> {
+    vec![mpl::SetAndVerifyCollection {
+        collection: *collection_metadata,
+        collection_authority: *collection_authority,
+        collection_authority_record,
+        collection_master_edition_account: *collection_master_edition,
+        collection_mint: *collection_mint,
+        metadata: *metadata,
+        payer: *payer,
+        update_authority: *update_authority,
+    }
+    .instruction()]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn set_and_verify_sized_collection_item(
+    metadata: &Pubkey,
+    collection_authority: &Pubkey,
+    payer: &Pubkey,
+    update_authority: &Pubkey,
+    collection_mint: &Pubkey,
+    collection_metadata: &Pubkey,
+    collection_master_edition: &Pubkey,
+    collection_authority_record: Option<Pubkey>,
+) -> Vec<Instruction> {
+    vec![mpl::SetAndVerifySizedCollectionItem {
+        collection: *collection_metadata,
+        collection_authority: *collection_authority,
+        collection_authority_record,
+        collection_master_edition_account: *collection_master_edition,
+        collection_mint: *collection_mint,
+        metadata: *metadata,
+        payer: *payer,
+        update_authority: *update_authority,
+    }
+    .instruction()]
+}
+
+pub fn freeze_delegated_account(
+    delegate: &Pubkey,
+    token_account: &Pubkey,
+    edition: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Vec<Instruction> {
+    vec![mpl::FreezeDelegatedAccount {
+        delegate: *delegate,
+        edition: *edition,
+        mint: *mint,
+        token_account: *token_account,
+        token_program: *token_program,
+    }
+    .instruction()]
+}
+
+pub fn thaw_delegated_account(
+    delegate: &Pubkey,
+    token_account: &Pubkey,
+    edition: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Vec<Instruction> {
+    vec![mpl::ThawDelegatedAccount {
+        delegate: *delegate,
+        edition: *edition,
+        mint: *mint,
+        token_account: *token_account,
+        token_program: *token_program,
+    }
+    .instruction()]
+}
+
+pub fn update_primary_sale_happened_via_token(
+    metadata: &Pubkey,
+    owner: &Pubkey,
+    token: &Pubkey,
+) -> Vec<Instruction> {
+    vec![mpl::UpdatePrimarySaleHappenedViaToken {
+        metadata: *metadata,
+        owner: *owner,
+        token: *token,
+    }
+    .instruction()]
+}
+
+pub fn set_token_standard(
+    metadata: &Pubkey,
+    update_authority: &Pubkey,
+    mint: &Pubkey,
+    edition: Option<Pubkey>,
+) -> Vec<Instruction> {
+    vec![mpl::SetTokenStandard {
+        edition,
+        metadata: *metadata,
+        mint: *mint,
+        update_authority: *update_authority,
+    }
+    .instruction()]
+}
+
+pub fn sign_metadata(creator: &Pubkey, metadata: &Pubkey) -> Vec<Instruction> {
+    vec![mpl::SignMetadata {
+        creator: *creator,
+        metadata: *metadata,
+    }
+    .instruction()]
+}
+
+pub fn remove_creator_verification(creator: &Pubkey, metadata: &Pubkey) -> Vec<Instruction> {
+    vec![mpl::RemoveCreatorVerification {
+        creator: *creator,
+        metadata: *metadata,
+    }
+    .instruction()]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn utilize(
+    metadata: &Pubkey,
+    token_account: &Pubkey,
+    mint: &Pubkey,
+    use_authority: &Pubkey,
+    owner: &Pubkey,
+    use_authority_record: Option<Pubkey>,
+    burner: Option<Pubkey>,
+    number_of_uses: u64,
+) -> Vec<Instruction> {
+    vec![mpl::Utilize {
+        ata_program: spl_associated_token_account::ID,
+        burner,
+        metadata: *metadata,
+        mint: *mint,
+        owner: *owner,
+        rent: sysvar::rent::ID,
+        system_program: system_program::ID,
+        token_account: *token_account,
+        token_program: spl_token::ID,
+        use_authority: *use_authority,
+        use_authority_record,
+    }
+    .instruction(mpl::UtilizeInstructionArgs { number_of_uses })]
+}
+
+pub fn unverify_collection(
+    metadata: &Pubkey,
+    collection_authority: &Pubkey,
+    collection_mint: &Pubkey,
+    collection_metadata: &Pubkey,
+    collection_master_edition: &Pubkey,
+    collection_authority_record: Option<Pubkey>,
+) -> Vec<Instruction> {
+    vec![mpl::UnverifyCollection {
+        collection: *collection_metadata,
+        collection_authority: *collection_authority,
+        collection_authority_record,
+        collection_master_edition_account: *collection_master_edition,
+        collection_mint: *collection_mint,
+        metadata: *metadata,
+    }
+    .instruction()]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn unverify_sized_collection_item(
+    metadata: &Pubkey,
+    collection_authority: &Pubkey,
+    payer: &Pubkey,
+    collection_mint: &Pubkey,
+    collection_metadata: &Pubkey,
+    collection_master_edition: &Pubkey,
+    collection_authority_record: Option<Pubkey>,
+) -> Vec<Instruction> {
+    vec![mpl::UnverifySizedCollectionItem {
+        collection: *collection_metadata,
+        collection_authority: *collection_authority,
+        collection_authority_record,
+        collection_master_edition_account: *collection_master_edition,
+        collection_mint: *collection_mint,
+        metadata: *metadata,
+        payer: *payer,
+    }
+    .instruction()]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_v1(
+    metadata: &Pubkey,
+    mint: &Pubkey,
+    mint_is_signer: bool,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    update_authority: &Pubkey,
+    update_authority_is_signer: bool,
+    spl_token_program: &Pubkey,
+    master_edition: Option<Pubkey>,
+    args: CreateArgs,
+) -> Vec<Instruction> {
+    vec![mpl::Create {
+        metadata: *metadata,
+        master_edition,
+        mint: (*mint, mint_is_signer),
+        authority: *authority,
+        payer: *payer,
+        update_authority: (*update_authority, update_authority_is_signer),
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: *spl_token_program,
+    }
+    .instruction(mpl::CreateInstructionArgs { create_args: args })]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn mint_v1(
+    token: &Pubkey,
+    metadata: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    spl_token_program: &Pubkey,
+    token_owner: Option<Pubkey>,
+    master_edition: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    delegate_record: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    args: MintArgs,
+) -> Vec<Instruction> {
+    vec![mpl::Mint {
+        token: *token,
+        token_owner,
+        metadata: *metadata,
+        master_edition,
+        token_record,
+        mint: *mint,
+        authority: *authority,
+        delegate_record,
+        payer: *payer,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: *spl_token_program,
+        spl_ata_program: spl_associated_token_account::ID,
+        authorization_rules_program: authorization_rules.map(|_| AUTH_RULES_PROGRAM_ID),
+        authorization_rules,
+    }
+    .instruction(mpl::MintInstructionArgs { mint_args: args })]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_v1(
+    token: &Pubkey,
+    token_owner: &Pubkey,
+    destination_token: &Pubkey,
+    destination_owner: &Pubkey,
+    mint: &Pubkey,
+    metadata: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    spl_token_program: &Pubkey,
+    edition: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    destination_token_record: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    args: TransferArgs,
+) -> Vec<Instruction> {
+    vec![mpl::Transfer {
+        token: *token,
+        token_owner: *token_owner,
+        destination_token: *destination_token,
+        destination_owner: *destination_owner,
+        mint: *mint,
+        metadata: *metadata,
+        edition,
+        token_record,
+        destination_token_record,
+        authority: *authority,
+        payer: *payer,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: *spl_token_program,
+        spl_ata_program: spl_associated_token_account::ID,
+        authorization_rules_program: authorization_rules.map(|_| AUTH_RULES_PROGRAM_ID),
+        authorization_rules,
+    }
+    .instruction(mpl::TransferInstructionArgs {
+        transfer_args: args,
+    })]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_v1(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    metadata: &Pubkey,
+    payer: &Pubkey,
+    delegate_record: Option<Pubkey>,
+    token: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    args: UpdateArgs,
+) -> Vec<Instruction> {
+    vec![mpl::Update {
+        authority: *authority,
+        delegate_record,
+        token,
+        mint: *mint,
+        metadata: *metadata,
+        edition,
+        payer: *payer,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        authorization_rules_program: authorization_rules.map(|_| AUTH_RULES_PROGRAM_ID),
+        authorization_rules,
+    }
+    .instruction(mpl::UpdateInstructionArgs { update_args: args })]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn burn_v1(
+    authority: &Pubkey,
+    metadata: &Pubkey,
+    mint: &Pubkey,
+    token: &Pubkey,
+    spl_token_program: &Pubkey,
+    collection_metadata: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    master_edition: Option<Pubkey>,
+    master_edition_mint: Option<Pubkey>,
+    master_edition_token: Option<Pubkey>,
+    edition_marker: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    args: BurnArgs,
+) -> Vec<Instruction> {
+    vec![mpl::Burn {
+        authority: *authority,
+        collection_metadata,
+        metadata: *metadata,
+        edition,
+        mint: *mint,
+        token: *token,
+        master_edition,
+        master_edition_mint,
+        master_edition_token,
+        edition_marker,
+        token_record,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: *spl_token_program,
+    }
+    .instruction(mpl::BurnInstructionArgs { burn_args: args })]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn delegate_v1(
+    delegate: &Pubkey,
+    metadata: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    spl_token_program: &Pubkey,
+    delegate_record: Option<Pubkey>,
+    master_edition: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    token: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    args: DelegateArgs,
+) -> Vec<Instruction> {
+    vec![mpl::Delegate {
+        delegate_record,
+        delegate: *delegate,
+        metadata: *metadata,
+        master_edition,
+        token_record,
+        mint: *mint,
+        token,
+        authority: *authority,
+        payer: *payer,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: token.map(|_| *spl_token_program),
+        authorization_rules_program: authorization_rules.map(|_| AUTH_RULES_PROGRAM_ID),
+        authorization_rules,
+    }
+    .instruction(mpl::DelegateInstructionArgs {
+        delegate_args: args,
+    })]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn revoke_v1(
+    delegate: &Pubkey,
+    metadata: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    spl_token_program: &Pubkey,
+    delegate_record: Option<Pubkey>,
+    master_edition: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    token: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    args: RevokeArgs,
+) -> Vec<Instruction> {
+    vec![mpl::Revoke {
+        delegate_record,
+        delegate: *delegate,
+        metadata: *metadata,
+        master_edition,
+        token_record,
+        mint: *mint,
+        token,
+        authority: *authority,
+        payer: *payer,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: token.map(|_| *spl_token_program),
+        authorization_rules_program: authorization_rules.map(|_| AUTH_RULES_PROGRAM_ID),
+        authorization_rules,
+    }
+    .instruction(mpl::RevokeInstructionArgs { revoke_args: args })]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn lock_v1(
+    authority: &Pubkey,
+    token: &Pubkey,
+    mint: &Pubkey,
+    metadata: &Pubkey,
+    payer: &Pubkey,
+    spl_token_program: &Pubkey,
+    token_owner: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    args: LockArgs,
+) -> Vec<Instruction> {
+    vec![mpl::Lock {
+        authority: *authority,
+        token_owner,
+        token: *token,
+        mint: *mint,
+        metadata: *metadata,
+        edition,
+        token_record,
+        payer: *payer,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: Some(*spl_token_program),
+        authorization_rules_program: authorization_rules.map(|_| AUTH_RULES_PROGRAM_ID),
+        authorization_rules,
+    }
+    .instruction(mpl::LockInstructionArgs { lock_args: args })]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn unlock_v1(
+    authority: &Pubkey,
+    token: &Pubkey,
+    mint: &Pubkey,
+    metadata: &Pubkey,
+    payer: &Pubkey,
+    spl_token_program: &Pubkey,
+    token_owner: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    args: UnlockArgs,
+) -> Vec<Instruction> {
+    vec![mpl::Unlock {
+        authority: *authority,
+        token_owner,
+        token: *token,
+        mint: *mint,
+        metadata: *metadata,
+        edition,
+        token_record,
+        payer: *payer,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: Some(*spl_token_program),
+        authorization_rules_program: authorization_rules.map(|_| AUTH_RULES_PROGRAM_ID),
+        authorization_rules,
+    }
+    .instruction(mpl::UnlockInstructionArgs { unlock_args: args })]
+}
+
+pub fn verify_v1(
+    authority: &Pubkey,
+    metadata: &Pubkey,
+    delegate_record: Option<Pubkey>,
+    collection_mint: Option<Pubkey>,
+    collection_metadata: Option<Pubkey>,
+    collection_master_edition: Option<Pubkey>,
+    args: VerificationArgs,
+) -> Vec<Instruction> {
+    vec![mpl::Verify {
+        authority: *authority,
+        delegate_record,
+        metadata: *metadata,
+        collection_mint,
+        collection_metadata,
+        collection_master_edition,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+    }
+    .instruction(mpl::VerifyInstructionArgs {
+        verification_args: args,
+    })]
+}
+
+pub fn unverify_v1(
+    authority: &Pubkey,
+    metadata: &Pubkey,
+    delegate_record: Option<Pubkey>,
+    collection_mint: Option<Pubkey>,
+    collection_metadata: Option<Pubkey>,
+    args: VerificationArgs,
+) -> Vec<Instruction> {
+    vec![mpl::Unverify {
+        authority: *authority,
+        delegate_record,
+        metadata: *metadata,
+        collection_mint,
+        collection_metadata,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+    }
+    .instruction(mpl::UnverifyInstructionArgs {
+        verification_args: args,
+    })]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn print_v1(
+    edition_metadata: &Pubkey,
+    edition: &Pubkey,
+    edition_mint: &Pubkey,
+    edition_token_account_owner: &Pubkey,
+    edition_token_account: &Pubkey,
+    edition_mint_authority: &Pubkey,
+    master_edition: &Pubkey,
+    edition_marker: &Pubkey,
+    payer: &Pubkey,
+    master_token_account_owner: &Pubkey,
+    master_token_account: &Pubkey,
+    master_metadata: &Pubkey,
+    update_authority: &Pubkey,
+    spl_token_program: &Pubkey,
+    edition_token_record: Option<Pubkey>,
+    args: PrintArgs,
+) -> Vec<Instruction> {
+    vec![mpl::Print {
+        edition_metadata: *edition_metadata,
+        edition: *edition,
+        edition_mint: *edition_mint,
+        edition_token_account_owner: *edition_token_account_owner,
+        edition_token_account: *edition_token_account,
+        edition_mint_authority: *edition_mint_authority,
+        edition_token_record,
+        master_edition: *master_edition,
+        edition_marker_pda: *edition_marker,
+        payer: *payer,
+        master_token_account_owner: *master_token_account_owner,
+        master_token_account: *master_token_account,
+        master_metadata: *master_metadata,
+        update_authority: *update_authority,
+        spl_token_program: *spl_token_program,
+        spl_ata_program: spl_associated_token_account::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        system_program: system_program::ID,
+    }
+    .instruction(mpl::PrintInstructionArgs { print_args: args })]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn use_v1(
+    authority: &Pubkey,
+    mint: &Pubkey,
+    metadata: &Pubkey,
+    payer: &Pubkey,
+    spl_token_program: &Pubkey,
+    delegate_record: Option<Pubkey>,
+    token: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    args: UseArgs,
+) -> Vec<Instruction> {
+    vec![mpl::Use {
+        authority: *authority,
+        delegate_record,
+        token,
+        mint: *mint,
+        metadata: *metadata,
+        edition,
+        payer: *payer,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: token.map(|_| *spl_token_program),
+        authorization_rules_program: authorization_rules.map(|_| AUTH_RULES_PROGRAM_ID),
+        authorization_rules,
+    }
+    .instruction(mpl::UseInstructionArgs { use_args: args })]
+}
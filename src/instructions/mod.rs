@@ -0,0 +1,14 @@
+#[cfg(feature = "associated_token")]
+pub mod associated_token;
+
+#[cfg(feature = "metadata")]
+pub mod metadata;
+
+#[cfg(feature = "token")]
+pub mod token;
+
+#[cfg(feature = "token_2022")]
+pub mod token_2022;
+
+#[cfg(feature = "simplespl")]
+pub mod simplespl;
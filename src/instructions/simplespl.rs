@@ -0,0 +1,1421 @@
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_lang::solana_program::system_instruction;
+use anchor_lang::Result;
+use mpl_token_metadata::types::{
+    AuthorizationData, Collection, CollectionDetails, Creator, DataV2, LockArgs, TransferArgs,
+    UnlockArgs,
+};
+use spl_token_2022::extension::ExtensionType;
+use spl_token_2022::instruction::AuthorityType;
+
+use super::{associated_token, metadata, token_2022};
+use crate::metadata::{
+    find_collection_authority_record, find_edition, find_edition_marker, find_master_edition,
+    find_metadata, find_token_record,
+};
+use crate::simplespl::{
+    required, LaunchTokenArgs, MintNftArgs, MintWithTransferFeeArgs, PnftDelegateRole,
+    SimpleMetadata,
+};
+
+/// Builds the instructions performed by `simplespl::mint_simple`: the metadata
+/// account is created and `amount` tokens are minted to `to`.
+#[allow(clippy::too_many_arguments)]
+pub fn mint_simple(
+    token_name: String,
+    token_symbol: String,
+    token_uri: String,
+    token_tax: u16,
+    payer: &Pubkey,
+    update_authority: &Pubkey,
+    metadata: &Pubkey,
+    mint_authority: &Pubkey,
+    mint: &Pubkey,
+    to: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) -> Result<Vec<Instruction>> {
+    let mut instructions = metadata_thing(
+        SimpleMetadata {
+            name: token_name,
+            symbol: token_symbol,
+            uri: token_uri,
+            seller_fee_basis_points: token_tax,
+            ..Default::default()
+        },
+        payer,
+        update_authority,
+        mint,
+        metadata,
+        mint_authority,
+    )?;
+    instructions.extend(token_2022::mint_to(
+        &spl_token::ID,
+        mint,
+        to,
+        owner,
+        &[],
+        amount,
+    )?);
+    Ok(instructions)
+}
+
+/// Builds the instructions performed by `simplespl::SimpleMint`, with the same
+/// opt-in steps: metadata is created when `metadata` is set, the associated token
+/// account of `owner` is created when `create_recipient_ata(true)` is set, and
+/// tokens are minted to `to` when `amount` is set. The metadata PDA is derived
+/// from `mint`.
+///
+/// `instructions` fails with `SplSimplifiedError::MissingAccount` if an account
+/// needed by an enabled step was not set; the error names the missing account as
+/// its account name.
+#[derive(Default)]
+pub struct SimpleMint {
+    payer: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    mint_authority: Option<Pubkey>,
+    token_program: Option<Pubkey>,
+    update_authority: Option<Pubkey>,
+    to: Option<Pubkey>,
+    owner: Option<Pubkey>,
+    metadata: Option<SimpleMetadata>,
+    amount: Option<u64>,
+    create_recipient_ata: bool,
+}
+
+impl SimpleMint {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+
+    pub fn mint_authority(mut self, mint_authority: Pubkey) -> Self {
+        self.mint_authority = Some(mint_authority);
+        self
+    }
+
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+
+    pub fn update_authority(mut self, update_authority: Pubkey) -> Self {
+        self.update_authority = Some(update_authority);
+        self
+    }
+
+    /// The token account receiving the initial supply.
+    pub fn to(mut self, to: Pubkey) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// The owner of the associated token account created by `create_recipient_ata`.
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+
+    /// Creates the metadata account of the mint.
+    pub fn metadata(mut self, metadata: SimpleMetadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Mints `amount` tokens to `to`.
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Creates the associated token account of `owner` if it does not exist yet.
+    pub fn create_recipient_ata(mut self, create_recipient_ata: bool) -> Self {
+        self.create_recipient_ata = create_recipient_ata;
+        self
+    }
+
+    pub fn instructions(self) -> Result<Vec<Instruction>> {
+        let mint = required(self.mint, "mint")?;
+        let mut instructions = Vec::new();
+
+        if let Some(token_data) = self.metadata {
+            instructions.extend(metadata_thing(
+                token_data,
+                &required(self.payer, "payer")?,
+                &required(self.update_authority, "update_authority")?,
+                &mint,
+                &find_metadata(&mint).0,
+                &required(self.mint_authority, "mint_authority")?,
+            )?);
+        }
+
+        if self.create_recipient_ata {
+            instructions.extend(associated_token::create_idempotent(
+                &required(self.payer, "payer")?,
+                &required(self.owner, "owner")?,
+                &mint,
+                &required(self.token_program, "token_program")?,
+            ));
+        }
+
+        if let Some(amount) = self.amount {
+            instructions.extend(token_2022::mint_to(
+                &required(self.token_program, "token_program")?,
+                &mint,
+                &required(self.to, "to")?,
+                &required(self.mint_authority, "mint_authority")?,
+                &[],
+                amount,
+            )?);
+        }
+
+        Ok(instructions)
+    }
+}
+
+/// Builds the instructions performed by `simplespl::mint_with_transfer_fee_simple`.
+/// The metadata PDA and the recipient's associated token account are derived
+/// from `mint`.
+///
+/// The program reads the rent from the `Rent` sysvar, off-chain the caller has to
+/// pass the `lamports` needed to make the mint rent exempt; `mint_space` returns
+/// the size to query the rent for.
+pub fn mint_with_transfer_fee_simple(
+    payer: &Pubkey,
+    mint: &Pubkey,
+    mint_authority: &Pubkey,
+    update_authority: &Pubkey,
+    recipient: &Pubkey,
+    lamports: u64,
+    args: MintWithTransferFeeArgs,
+) -> Result<Vec<Instruction>> {
+    let token_program = spl_token_2022::ID;
+    let recipient_token_account =
+        spl_associated_token_account::get_associated_token_address_with_program_id(
+            recipient,
+            mint,
+            &token_program,
+        );
+
+    let mut instructions = vec![system_instruction::create_account(
+        payer,
+        mint,
+        lamports,
+        mint_space()? as u64,
+        &token_program,
+    )];
+    instructions.extend(token_2022::initialize_transfer_fee_config(
+        &token_program,
+        mint,
+        Some(mint_authority),
+        Some(mint_authority),
+        args.transfer_fee_basis_points,
+        args.maximum_fee,
+    )?);
+    instructions.extend(token_2022::initialize_mint2(
+        &token_program,
+        mint,
+        args.decimals,
+        mint_authority,
+        None,
+    )?);
+    instructions.extend(metadata_thing(
+        args.metadata,
+        payer,
+        update_authority,
+        mint,
+        &find_metadata(mint).0,
+        mint_authority,
+    )?);
+    instructions.extend(associated_token::create_idempotent(
+        payer,
+        recipient,
+        mint,
+        &token_program,
+    ));
+    instructions.extend(token_2022::mint_to(
+        &token_program,
+        mint,
+        &recipient_token_account,
+        mint_authority,
+        &[],
+        args.amount,
+    )?);
+    Ok(instructions)
+}
+
+/// Size of a mint created by `mint_with_transfer_fee_simple`.
+pub fn mint_space() -> Result<usize> {
+    ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::TransferFeeConfig,
+    ])
+    .map_err(Into::into)
+}
+
+/// Builds the instructions performed by `simplespl::launch_token_simple`. The
+/// metadata PDA and the recipient's associated token account are derived from
+/// `mint`.
+///
+/// The program reads the rent from the `Rent` sysvar, off-chain the caller has to
+/// pass the `lamports` needed to make a mint of `spl_token::state::Mint::LEN`
+/// bytes rent exempt.
+#[allow(clippy::too_many_arguments)]
+pub fn launch_token_simple(
+    payer: &Pubkey,
+    mint: &Pubkey,
+    mint_authority: &Pubkey,
+    update_authority: &Pubkey,
+    recipient: &Pubkey,
+    token_program: &Pubkey,
+    lamports: u64,
+    args: LaunchTokenArgs,
+) -> Result<Vec<Instruction>> {
+    let recipient_token_account =
+        spl_associated_token_account::get_associated_token_address_with_program_id(
+            recipient,
+            mint,
+            token_program,
+        );
+
+    let mut instructions = vec![system_instruction::create_account(
+        payer,
+        mint,
+        lamports,
+        spl_token::state::Mint::LEN as u64,
+        token_program,
+    )];
+    instructions.extend(token_2022::initialize_mint2(
+        token_program,
+        mint,
+        args.decimals,
+        mint_authority,
+        Some(mint_authority),
+    )?);
+    instructions.extend(associated_token::create_idempotent(
+        payer,
+        recipient,
+        mint,
+        token_program,
+    ));
+    instructions.extend(metadata_thing(
+        args.metadata,
+        payer,
+        update_authority,
+        mint,
+        &find_metadata(mint).0,
+        mint_authority,
+    )?);
+    if args.amount > 0 {
+        instructions.extend(token_2022::mint_to(
+            token_program,
+            mint,
+            &recipient_token_account,
+            mint_authority,
+            &[],
+            args.amount,
+        )?);
+    }
+
+    let revocations = [
+        (args.revoke_mint_authority, AuthorityType::MintTokens),
+        (args.revoke_freeze_authority, AuthorityType::FreezeAccount),
+    ];
+    for (revoke, authority_type) in revocations {
+        if revoke {
+            instructions.extend(token_2022::set_authority(
+                token_program,
+                mint,
+                mint_authority,
+                &[],
+                authority_type,
+                None,
+            )?);
+        }
+    }
+
+    Ok(instructions)
+}
+
+/// The collection a [`mint_nft_simple`] NFT is added to and verified in. Its
+/// metadata and master edition PDAs are derived from `collection_mint`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NftCollection {
+    /// The update authority of the collection, or a delegate with a collection authority record.
+    pub collection_authority: Pubkey,
+    pub collection_mint: Pubkey,
+    /// Set when `collection_authority` is a delegate; the record PDA is derived from it.
+    pub delegated: bool,
+    /// Whether the collection is sized, which the program reads from the collection metadata.
+    pub sized: bool,
+}
+
+impl NftCollection {
+    fn authority_record(&self) -> Option<Pubkey> {
+        self.delegated.then(|| {
+            find_collection_authority_record(&self.collection_mint, &self.collection_authority).0
+        })
+    }
+}
+
+/// Builds the instructions performed by `simplespl::mint_nft_simple`. The
+/// metadata and master edition PDAs and the owner's associated token account are
+/// derived from `mint`; NFTs are minted with the legacy Token program.
+///
+/// The program reads the rent from the `Rent` sysvar, off-chain the caller has to
+/// pass the `lamports` needed to make a mint of `spl_token::state::Mint::LEN`
+/// bytes rent exempt.
+#[allow(clippy::too_many_arguments)]
+pub fn mint_nft_simple(
+    payer: &Pubkey,
+    mint: &Pubkey,
+    mint_authority: &Pubkey,
+    update_authority: &Pubkey,
+    owner: &Pubkey,
+    lamports: u64,
+    collection: Option<NftCollection>,
+    args: MintNftArgs,
+) -> Result<Vec<Instruction>> {
+    let token_program = spl_token::ID;
+    let owner_token_account =
+        spl_associated_token_account::get_associated_token_address_with_program_id(
+            owner,
+            mint,
+            &token_program,
+        );
+    let metadata = find_metadata(mint).0;
+    let mut metadata_args = args.metadata;
+    if let Some(collection) = &collection {
+        metadata_args.collection = Some(collection.collection_mint);
+    }
+
+    let mut instructions = vec![system_instruction::create_account(
+        payer,
+        mint,
+        lamports,
+        spl_token::state::Mint::LEN as u64,
+        &token_program,
+    )];
+    instructions.extend(token_2022::initialize_mint2(
+        &token_program,
+        mint,
+        0,
+        mint_authority,
+        Some(mint_authority),
+    )?);
+    instructions.extend(associated_token::create_idempotent(
+        payer,
+        owner,
+        mint,
+        &token_program,
+    ));
+    instructions.extend(token_2022::mint_to(
+        &token_program,
+        mint,
+        &owner_token_account,
+        mint_authority,
+        &[],
+        1,
+    )?);
+    instructions.extend(metadata_thing(
+        metadata_args,
+        payer,
+        update_authority,
+        mint,
+        &metadata,
+        mint_authority,
+    )?);
+    instructions.extend(metadata::create_master_edition_v3(
+        &find_master_edition(mint).0,
+        mint,
+        update_authority,
+        mint_authority,
+        payer,
+        &metadata,
+        args.max_supply,
+    ));
+
+    let Some(collection) = collection else {
+        return Ok(instructions);
+    };
+    let verify = if collection.sized {
+        metadata::verify_sized_collection_item
+    } else {
+        metadata::verify_collection
+    };
+    instructions.extend(verify(
+        payer,
+        &metadata,
+        &collection.collection_authority,
+        &collection.collection_mint,
+        &find_metadata(&collection.collection_mint).0,
+        &find_master_edition(&collection.collection_mint).0,
+        collection.authority_record(),
+    ));
+    Ok(instructions)
+}
+
+/// Builds the instructions performed by `simplespl::create_collection_simple`.
+#[allow(clippy::too_many_arguments)]
+pub fn create_collection_simple(
+    payer: &Pubkey,
+    mint: &Pubkey,
+    mint_authority: &Pubkey,
+    update_authority: &Pubkey,
+    owner: &Pubkey,
+    lamports: u64,
+    mut args: MintNftArgs,
+) -> Result<Vec<Instruction>> {
+    args.metadata.collection_details = Some(CollectionDetails::V1 { size: 0 });
+    mint_nft_simple(
+        payer,
+        mint,
+        mint_authority,
+        update_authority,
+        owner,
+        lamports,
+        None,
+        args,
+    )
+}
+
+/// Accounts used by [`add_to_collection_simple`] and [`remove_from_collection_simple`].
+/// The metadata PDAs are derived from the mints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CollectionItem {
+    pub payer: Pubkey,
+    pub item_mint: Pubkey,
+    /// The update authority of the item, needed when the item does not point to the
+    /// collection yet.
+    pub item_update_authority: Pubkey,
+    pub collection: NftCollection,
+}
+
+/// Builds the instruction performed by `simplespl::add_to_collection_simple`.
+///
+/// The program reads the item metadata to know whether it already points to the
+/// collection, off-chain it is passed in as `points_to_collection`. Items that are
+/// already verified members have nothing to build.
+pub fn add_to_collection_simple(
+    accounts: CollectionItem,
+    points_to_collection: bool,
+) -> Vec<Instruction> {
+    let CollectionItem {
+        payer,
+        item_mint,
+        item_update_authority,
+        collection,
+    } = accounts;
+    let metadata = find_metadata(&item_mint).0;
+    let collection_metadata = find_metadata(&collection.collection_mint).0;
+    let collection_master_edition = find_master_edition(&collection.collection_mint).0;
+    let record = collection.authority_record();
+
+    match (points_to_collection, collection.sized) {
+        (true, true) => metadata::verify_sized_collection_item(
+            &payer,
+            &metadata,
+            &collection.collection_authority,
+            &collection.collection_mint,
+            &collection_metadata,
+            &collection_master_edition,
+            record,
+        ),
+        (true, false) => metadata::verify_collection(
+            &payer,
+            &metadata,
+            &collection.collection_authority,
+            &collection.collection_mint,
+            &collection_metadata,
+            &collection_master_edition,
+            record,
+        ),
+        (false, true) => metadata::set_and_verify_sized_collection_item(
+            &metadata,
+            &collection.collection_authority,
+            &payer,
+            &item_update_authority,
+            &collection.collection_mint,
+            &collection_metadata,
+            &collection_master_edition,
+            record,
+        ),
+        (false, false) => metadata::set_and_verify_collection(
+            &metadata,
+            &collection.collection_authority,
+            &payer,
+            &item_update_authority,
+            &collection.collection_mint,
+            &collection_metadata,
+            &collection_master_edition,
+            record,
+        ),
+    }
+}
+
+/// Builds the instruction performed by `simplespl::remove_from_collection_simple`.
+/// `item_update_authority` is not used.
+pub fn remove_from_collection_simple(accounts: CollectionItem) -> Vec<Instruction> {
+    let CollectionItem {
+        payer,
+        item_mint,
+        collection,
+        ..
+    } = accounts;
+    let metadata = find_metadata(&item_mint).0;
+    let collection_metadata = find_metadata(&collection.collection_mint).0;
+    let collection_master_edition = find_master_edition(&collection.collection_mint).0;
+
+    if collection.sized {
+        metadata::unverify_sized_collection_item(
+            &metadata,
+            &collection.collection_authority,
+            &payer,
+            &collection.collection_mint,
+            &collection_metadata,
+            &collection_master_edition,
+            collection.authority_record(),
+        )
+    } else {
+        metadata::unverify_collection(
+            &metadata,
+            &collection.collection_authority,
+            &collection.collection_mint,
+            &collection_metadata,
+            &collection_master_edition,
+            collection.authority_record(),
+        )
+    }
+}
+
+/// Builds the instruction performed by `simplespl::migrate_collection_to_sized_simple`.
+/// The `sized` flag of `collection` is not used.
+pub fn migrate_collection_to_sized_simple(
+    collection: NftCollection,
+    size: u64,
+) -> Vec<Instruction> {
+    metadata::set_collection_size(
+        &find_metadata(&collection.collection_mint).0,
+        &collection.collection_mint,
+        &collection.collection_authority,
+        collection.authority_record(),
+        size,
+    )
+}
+
+/// Accounts used by [`print_edition_simple`]. The metadata, edition and edition
+/// marker PDAs are derived from the mints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrintEdition {
+    pub payer: Pubkey,
+    /// The mint of the print. Must not exist yet and must sign.
+    pub new_mint: Pubkey,
+    pub new_mint_authority: Pubkey,
+    pub new_metadata_update_authority: Pubkey,
+    /// The wallet receiving the print in its associated token account.
+    pub recipient: Pubkey,
+    /// The mint of the master edition NFT.
+    pub master_mint: Pubkey,
+    /// The token account holding the master edition NFT.
+    pub master_token_account: Pubkey,
+    pub master_token_account_owner: Pubkey,
+}
+
+/// Builds the instructions performed by `simplespl::print_edition_simple`.
+///
+/// The program reads the edition number from the master edition supply and the
+/// rent from the `Rent` sysvar, off-chain both are passed in: `edition` is the
+/// master edition's current supply plus one, and `lamports` makes a mint of
+/// `spl_token::state::Mint::LEN` bytes rent exempt.
+pub fn print_edition_simple(
+    accounts: PrintEdition,
+    edition: u64,
+    lamports: u64,
+) -> Result<Vec<Instruction>> {
+    let token_program = spl_token::ID;
+    let recipient_token_account =
+        spl_associated_token_account::get_associated_token_address_with_program_id(
+            &accounts.recipient,
+            &accounts.new_mint,
+            &token_program,
+        );
+
+    let mut instructions = vec![system_instruction::create_account(
+        &accounts.payer,
+        &accounts.new_mint,
+        lamports,
+        spl_token::state::Mint::LEN as u64,
+        &token_program,
+    )];
+    instructions.extend(token_2022::initialize_mint2(
+        &token_program,
+        &accounts.new_mint,
+        0,
+        &accounts.new_mint_authority,
+        Some(&accounts.new_mint_authority),
+    )?);
+    instructions.extend(associated_token::create_idempotent(
+        &accounts.payer,
+        &accounts.recipient,
+        &accounts.new_mint,
+        &token_program,
+    ));
+    instructions.extend(token_2022::mint_to(
+        &token_program,
+        &accounts.new_mint,
+        &recipient_token_account,
+        &accounts.new_mint_authority,
+        &[],
+        1,
+    )?);
+    instructions.extend(metadata::mint_new_edition_from_master_edition_via_token(
+        &find_metadata(&accounts.new_mint).0,
+        &find_edition(&accounts.new_mint).0,
+        &find_master_edition(&accounts.master_mint).0,
+        &accounts.new_mint,
+        &find_edition_marker(&accounts.master_mint, edition).0,
+        &accounts.new_mint_authority,
+        &accounts.payer,
+        &accounts.master_token_account_owner,
+        &accounts.master_token_account,
+        &accounts.new_metadata_update_authority,
+        &find_metadata(&accounts.master_mint).0,
+        edition,
+    ));
+    Ok(instructions)
+}
+
+/// Accounts used by [`transfer_pnft_simple`]. The metadata, edition and token
+/// record PDAs are derived from `mint`, and `destination_token` is the associated
+/// token account of `destination_owner`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransferPnft {
+    pub payer: Pubkey,
+    /// The owner of `token`, or its transfer, sale or locked transfer delegate.
+    pub authority: Pubkey,
+    pub token_owner: Pubkey,
+    /// The token account holding the pNFT.
+    pub token: Pubkey,
+    pub destination_owner: Pubkey,
+    pub mint: Pubkey,
+    /// The rule set named by the metadata, which the program reads from it.
+    pub authorization_rules: Option<Pubkey>,
+}
+
+/// Builds the instruction performed by `simplespl::transfer_pnft_simple`.
+pub fn transfer_pnft_simple(
+    accounts: TransferPnft,
+    authorization_data: Option<AuthorizationData>,
+) -> Vec<Instruction> {
+    let destination_token =
+        spl_associated_token_account::get_associated_token_address_with_program_id(
+            &accounts.destination_owner,
+            &accounts.mint,
+            &spl_token::ID,
+        );
+    metadata::transfer_v1(
+        &accounts.token,
+        &accounts.token_owner,
+        &destination_token,
+        &accounts.destination_owner,
+        &accounts.mint,
+        &find_metadata(&accounts.mint).0,
+        &accounts.authority,
+        &accounts.payer,
+        &spl_token::ID,
+        Some(find_master_edition(&accounts.mint).0),
+        Some(find_token_record(&accounts.mint, &accounts.token).0),
+        Some(find_token_record(&accounts.mint, &destination_token).0),
+        accounts.authorization_rules,
+        TransferArgs::V1 {
+            amount: 1,
+            authorization_data,
+        },
+    )
+}
+
+/// Accounts used by [`delegate_pnft_simple`] and [`revoke_pnft_simple`]. The
+/// metadata, edition and token record PDAs are derived from `mint`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DelegatePnft {
+    pub payer: Pubkey,
+    /// The owner of `token`.
+    pub authority: Pubkey,
+    pub delegate: Pubkey,
+    pub mint: Pubkey,
+    /// The token account holding the pNFT.
+    pub token: Pubkey,
+    /// The rule set named by the metadata, which the program reads from it.
+    pub authorization_rules: Option<Pubkey>,
+}
+
+/// Builds the instruction performed by `simplespl::delegate_pnft_simple`.
+pub fn delegate_pnft_simple(
+    accounts: DelegatePnft,
+    role: PnftDelegateRole,
+    authorization_data: Option<AuthorizationData>,
+) -> Vec<Instruction> {
+    metadata::delegate_v1(
+        &accounts.delegate,
+        &find_metadata(&accounts.mint).0,
+        &accounts.mint,
+        &accounts.authority,
+        &accounts.payer,
+        &spl_token::ID,
+        None,
+        Some(find_master_edition(&accounts.mint).0),
+        Some(find_token_record(&accounts.mint, &accounts.token).0),
+        Some(accounts.token),
+        accounts.authorization_rules,
+        role.delegate_args(authorization_data),
+    )
+}
+
+/// Builds the instruction performed by `simplespl::revoke_pnft_simple`.
+pub fn revoke_pnft_simple(accounts: DelegatePnft, role: PnftDelegateRole) -> Vec<Instruction> {
+    metadata::revoke_v1(
+        &accounts.delegate,
+        &find_metadata(&accounts.mint).0,
+        &accounts.mint,
+        &accounts.authority,
+        &accounts.payer,
+        &spl_token::ID,
+        None,
+        Some(find_master_edition(&accounts.mint).0),
+        Some(find_token_record(&accounts.mint, &accounts.token).0),
+        Some(accounts.token),
+        accounts.authorization_rules,
+        role.revoke_args(),
+    )
+}
+
+/// Accounts used by [`lock_pnft_simple`] and [`unlock_pnft_simple`]. The metadata,
+/// edition and token record PDAs are derived from `mint`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LockPnft {
+    pub payer: Pubkey,
+    /// The utility, staking or locked transfer delegate of `token`.
+    pub authority: Pubkey,
+    pub token_owner: Pubkey,
+    /// The token account holding the pNFT.
+    pub token: Pubkey,
+    pub mint: Pubkey,
+    /// The rule set named by the metadata, which the program reads from it.
+    pub authorization_rules: Option<Pubkey>,
+}
+
+/// Builds the instruction performed by `simplespl::lock_pnft_simple`.
+pub fn lock_pnft_simple(
+    accounts: LockPnft,
+    authorization_data: Option<AuthorizationData>,
+) -> Vec<Instruction> {
+    metadata::lock_v1(
+        &accounts.authority,
+        &accounts.token,
+        &accounts.mint,
+        &find_metadata(&accounts.mint).0,
+        &accounts.payer,
+        &spl_token::ID,
+        Some(accounts.token_owner),
+        Some(find_master_edition(&accounts.mint).0),
+        Some(find_token_record(&accounts.mint, &accounts.token).0),
+        accounts.authorization_rules,
+        LockArgs::V1 { authorization_data },
+    )
+}
+
+/// Builds the instruction performed by `simplespl::unlock_pnft_simple`.
+pub fn unlock_pnft_simple(
+    accounts: LockPnft,
+    authorization_data: Option<AuthorizationData>,
+) -> Vec<Instruction> {
+    metadata::unlock_v1(
+        &accounts.authority,
+        &accounts.token,
+        &accounts.mint,
+        &find_metadata(&accounts.mint).0,
+        &accounts.payer,
+        &spl_token::ID,
+        Some(accounts.token_owner),
+        Some(find_master_edition(&accounts.mint).0),
+        Some(find_token_record(&accounts.mint, &accounts.token).0),
+        accounts.authorization_rules,
+        UnlockArgs::V1 { authorization_data },
+    )
+}
+
+fn metadata_thing(
+    mut token_data: SimpleMetadata,
+    payer: &Pubkey,
+    update_authority: &Pubkey,
+    mint: &Pubkey,
+    metadata: &Pubkey,
+    mint_authority: &Pubkey,
+) -> Result<Vec<Instruction>> {
+    token_data.creators.get_or_insert_with(|| {
+        vec![Creator {
+            address: *update_authority,
+            verified: true,
+            share: 100,
+        }]
+    });
+    token_data.validate(update_authority)?;
+
+    let data = DataV2 {
+        name: token_data.name,
+        symbol: token_data.symbol,
+        uri: token_data.uri,
+        seller_fee_basis_points: token_data.seller_fee_basis_points,
+        creators: token_data.creators,
+        collection: token_data.collection.map(|key| Collection {
+            verified: false,
+            key,
+        }),
+        uses: token_data.uses,
+    };
+
+    metadata::create_metadata_accounts_v3(
+        metadata,
+        mint,
+        mint_authority,
+        payer,
+        update_authority,
+        data,
+        token_data.is_mutable,
+        true,
+        token_data.collection_details,
+    )
+}
+
+/// Builds the instruction performed by `simplespl::transfer_simple` for token
+/// accounts; `decimals` are the decimals of `mint`. Use `wrap_sol` to wrap
+/// lamports from a system account instead.
+pub fn transfer_simple(
+    mint: &Pubkey,
+    token_program_id: &Pubkey,
+    source: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+    decimals: u8,
+) -> Result<Vec<Instruction>> {
+    token_2022::transfer_checked(
+        token_program_id,
+        source,
+        mint,
+        destination,
+        authority,
+        &[],
+        amount,
+        decimals,
+    )
+}
+
+pub fn burn_simple(
+    mint: &Pubkey,
+    token_program_id: &Pubkey,
+    source: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+) -> Result<Vec<Instruction>> {
+    token_2022::burn(token_program_id, source, mint, authority, &[], amount)
+}
+
+/// Builds the instruction performed by `simplespl::transfer_with_fee_simple`.
+///
+/// The program reads `decimals` and the fee from the mint, off-chain they are
+/// passed in; `calculate_epoch_fee` on the mint's `TransferFeeConfig` gives the fee.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_with_fee_simple(
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    source: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+    decimals: u8,
+    fee: u64,
+) -> Result<Vec<Instruction>> {
+    token_2022::transfer_checked_with_fee(
+        token_program,
+        source,
+        mint,
+        destination,
+        authority,
+        &[],
+        amount,
+        decimals,
+        fee,
+    )
+}
+
+/// Builds the instructions performed by `simplespl::wrap_sol`. When
+/// `token_account` is the associated token account of `owner` for the native
+/// mint of `token_program`, it is created first if missing.
+pub fn wrap_sol(
+    payer: &Pubkey,
+    owner: &Pubkey,
+    token_account: &Pubkey,
+    token_program: &Pubkey,
+    lamports: u64,
+) -> Result<Vec<Instruction>> {
+    let native_mint = if *token_program == spl_token_2022::ID {
+        spl_token_2022::native_mint::ID
+    } else {
+        spl_token::native_mint::ID
+    };
+    let mut instructions = Vec::new();
+    let ata = spl_associated_token_account::get_associated_token_address_with_program_id(
+        owner,
+        &native_mint,
+        token_program,
+    );
+    if *token_account == ata {
+        instructions.extend(associated_token::create_idempotent(
+            payer,
+            owner,
+            &native_mint,
+            token_program,
+        ));
+    }
+    instructions.push(system_instruction::transfer(payer, token_account, lamports));
+    instructions.extend(token_2022::sync_native(token_program, token_account)?);
+    Ok(instructions)
+}
+
+pub fn unwrap_sol(
+    token_account: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    token_program: &Pubkey,
+) -> Result<Vec<Instruction>> {
+    token_2022::close_account(token_program, token_account, destination, authority, &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::SplSimplifiedError;
+    use crate::metadata::{MasterEditionAccount, MetadataAccount, AUTH_RULES_PROGRAM_ID};
+    use crate::simplespl as cpi;
+    use crate::stubs;
+    use anchor_lang::error::{Error, ErrorOrigin};
+    use anchor_lang::solana_program::account_info::AccountInfo;
+    use anchor_lang::solana_program::rent::Rent;
+    use anchor_lang::solana_program::{system_program, sysvar};
+    use anchor_lang::AccountSerialize;
+    use mpl_token_metadata::types::{Key, ProgrammableConfig, TokenStandard};
+    use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+    // The accounts handed to the CPI wrappers are leaked, so they outlive the
+    // wrappers without threading lifetimes through every test.
+    fn account(key: Pubkey) -> AccountInfo<'static> {
+        account_with(key, Pubkey::default(), Vec::new())
+    }
+
+    fn account_with(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(key)),
+            false,
+            false,
+            Box::leak(Box::new(0)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            false,
+            0,
+        )
+    }
+
+    fn keys<const N: usize>() -> [Pubkey; N] {
+        std::array::from_fn(|_| Pubkey::new_unique())
+    }
+
+    fn serialized<T: AccountSerialize>(account: &T) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    fn account_name(error: &Error) -> Option<&str> {
+        match error {
+            Error::AnchorError(error) => match &error.error_origin {
+                Some(ErrorOrigin::AccountName(name)) => Some(name),
+                _ => None,
+            },
+            Error::ProgramError(_) => None,
+        }
+    }
+
+    fn token_metadata() -> SimpleMetadata {
+        SimpleMetadata {
+            name: "Token".to_string(),
+            symbol: "TKN".to_string(),
+            uri: "https://example.com/token.json".to_string(),
+            seller_fee_basis_points: 500,
+            ..Default::default()
+        }
+    }
+
+    // The metadata of a programmable NFT whose rule set is `rule_set`.
+    fn pnft_metadata(mint: Pubkey, rule_set: Pubkey) -> AccountInfo<'static> {
+        let metadata = MetadataAccount::new(mpl_token_metadata::accounts::Metadata {
+            key: Key::MetadataV1,
+            update_authority: Pubkey::new_unique(),
+            mint,
+            name: "Token".to_string(),
+            symbol: "TKN".to_string(),
+            uri: "https://example.com/token.json".to_string(),
+            seller_fee_basis_points: 500,
+            creators: None,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: Some(255),
+            token_standard: Some(TokenStandard::ProgrammableNonFungible),
+            collection: None,
+            uses: None,
+            collection_details: None,
+            programmable_config: Some(ProgrammableConfig::V1 {
+                rule_set: Some(rule_set),
+            }),
+        });
+        account_with(
+            find_metadata(&mint).0,
+            mpl_token_metadata::ID,
+            serialized(&metadata),
+        )
+    }
+
+    #[test]
+    fn mint_simple_matches_cpi() {
+        let [payer, update_authority, mint_authority, mint, to, owner] = keys();
+        let metadata = find_metadata(&mint).0;
+
+        let (result, invoked) = stubs::invoked(|| {
+            cpi::mint_simple(
+                "Token".to_string(),
+                "TKN".to_string(),
+                "https://example.com/token.json".to_string(),
+                500,
+                account(payer),
+                account(mpl_token_metadata::ID),
+                account(update_authority),
+                account(metadata),
+                account(mint_authority),
+                account(system_program::ID),
+                account(sysvar::rent::ID),
+                account(spl_token::ID),
+                account(mint),
+                account(to),
+                account(owner),
+                &[],
+                1_000,
+            )
+        });
+        result.unwrap();
+
+        let instructions = mint_simple(
+            "Token".to_string(),
+            "TKN".to_string(),
+            "https://example.com/token.json".to_string(),
+            500,
+            &payer,
+            &update_authority,
+            &metadata,
+            &mint_authority,
+            &mint,
+            &to,
+            &owner,
+            1_000,
+        )
+        .unwrap();
+        assert_eq!(invoked, instructions);
+    }
+
+    #[test]
+    fn simple_mint_matches_cpi() {
+        let [payer, update_authority, mint_authority, mint, owner] = keys();
+        let token_program = spl_token_2022::ID;
+        let to = get_associated_token_address_with_program_id(&owner, &mint, &token_program);
+
+        let (result, invoked) = stubs::invoked(|| {
+            cpi::SimpleMint::new()
+                .payer(account(payer))
+                .mint(account(mint))
+                .mint_authority(account(mint_authority))
+                .token_program(account(token_program))
+                .system_program(account(system_program::ID))
+                .rent(account(sysvar::rent::ID))
+                .token_metadata_program(account(mpl_token_metadata::ID))
+                .metadata_account(account(find_metadata(&mint).0))
+                .update_authority(account(update_authority))
+                .associated_token_program(account(spl_associated_token_account::ID))
+                .to(account(to))
+                .owner(account(owner))
+                .metadata(token_metadata())
+                .create_recipient_ata(true)
+                .amount(1_000)
+                .invoke()
+        });
+        result.unwrap();
+
+        let instructions = SimpleMint::new()
+            .payer(payer)
+            .mint(mint)
+            .mint_authority(mint_authority)
+            .token_program(token_program)
+            .update_authority(update_authority)
+            .to(to)
+            .owner(owner)
+            .metadata(token_metadata())
+            .create_recipient_ata(true)
+            .amount(1_000)
+            .instructions()
+            .unwrap();
+        assert_eq!(invoked, instructions);
+    }
+
+    #[test]
+    fn simple_mint_names_the_missing_account() {
+        let error = SimpleMint::new()
+            .mint(Pubkey::new_unique())
+            .amount(1)
+            .instructions()
+            .unwrap_err();
+        assert_eq!(error, SplSimplifiedError::MissingAccount.into());
+        assert_eq!(account_name(&error), Some("token_program"));
+
+        let error = SimpleMint::new().amount(1).instructions().unwrap_err();
+        assert_eq!(account_name(&error), Some("mint"));
+    }
+
+    #[test]
+    fn wrap_sol_matches_cpi() {
+        let native_mints = [
+            (spl_token::ID, spl_token::native_mint::ID),
+            (spl_token_2022::ID, spl_token_2022::native_mint::ID),
+        ];
+        for (token_program, native_mint) in native_mints {
+            let [payer, owner] = keys();
+            let token_account =
+                get_associated_token_address_with_program_id(&owner, &native_mint, &token_program);
+
+            let (result, invoked) = stubs::invoked(|| {
+                cpi::wrap_sol(
+                    account(payer),
+                    account(owner),
+                    account(token_account),
+                    account(native_mint),
+                    account(token_program),
+                    account(spl_associated_token_account::ID),
+                    account(system_program::ID),
+                    1_000,
+                    &[],
+                )
+            });
+            result.unwrap();
+
+            let instructions =
+                wrap_sol(&payer, &owner, &token_account, &token_program, 1_000).unwrap();
+            assert_eq!(invoked, instructions);
+        }
+    }
+
+    #[test]
+    fn print_edition_simple_matches_cpi() {
+        let [payer, new_mint, new_mint_authority, new_metadata_update_authority, recipient, master_mint, master_token_account, master_token_account_owner] =
+            keys();
+        let master_edition =
+            MasterEditionAccount::new(mpl_token_metadata::accounts::MasterEdition {
+                key: Key::MasterEditionV2,
+                supply: 3,
+                max_supply: Some(10),
+            });
+        let edition = 4;
+
+        let (result, invoked) = stubs::invoked(|| {
+            cpi::print_edition_simple(
+                cpi::PrintEdition {
+                    payer: account(payer),
+                    new_mint: account(new_mint),
+                    new_mint_authority: account(new_mint_authority),
+                    new_metadata: account(find_metadata(&new_mint).0),
+                    new_edition: account(find_edition(&new_mint).0),
+                    new_metadata_update_authority: account(new_metadata_update_authority),
+                    recipient: account(recipient),
+                    recipient_token_account: account(get_associated_token_address_with_program_id(
+                        &recipient,
+                        &new_mint,
+                        &spl_token::ID,
+                    )),
+                    master_mint: account(master_mint),
+                    master_metadata: account(find_metadata(&master_mint).0),
+                    master_edition: account_with(
+                        find_master_edition(&master_mint).0,
+                        mpl_token_metadata::ID,
+                        serialized(&master_edition),
+                    ),
+                    master_token_account: account(master_token_account),
+                    master_token_account_owner: account(master_token_account_owner),
+                    edition_marker: account(find_edition_marker(&master_mint, edition).0),
+                    token_program: account(spl_token::ID),
+                    associated_token_program: account(spl_associated_token_account::ID),
+                    token_metadata_program: account(mpl_token_metadata::ID),
+                    system_program: account(system_program::ID),
+                    rent: account(sysvar::rent::ID),
+                },
+                &[],
+            )
+        });
+        assert_eq!(result.unwrap(), edition);
+
+        let accounts = PrintEdition {
+            payer,
+            new_mint,
+            new_mint_authority,
+            new_metadata_update_authority,
+            recipient,
+            master_mint,
+            master_token_account,
+            master_token_account_owner,
+        };
+        let lamports = Rent::default().minimum_balance(spl_token::state::Mint::LEN);
+        let instructions = print_edition_simple(accounts, edition, lamports).unwrap();
+        assert_eq!(invoked, instructions);
+    }
+
+    #[test]
+    fn transfer_pnft_simple_matches_cpi() {
+        let [payer, authority, token_owner, token, destination_owner, mint, rule_set] = keys();
+        let destination_token =
+            get_associated_token_address_with_program_id(&destination_owner, &mint, &spl_token::ID);
+
+        let (result, invoked) = stubs::invoked(|| {
+            cpi::transfer_pnft_simple(
+                cpi::TransferPnft {
+                    payer: account(payer),
+                    authority: account(authority),
+                    token_owner: account(token_owner),
+                    token: account(token),
+                    destination_owner: account(destination_owner),
+                    destination_token: account(destination_token),
+                    mint: account(mint),
+                    metadata: pnft_metadata(mint, rule_set),
+                    edition: account(find_master_edition(&mint).0),
+                    token_record: account(find_token_record(&mint, &token).0),
+                    destination_token_record: account(
+                        find_token_record(&mint, &destination_token).0,
+                    ),
+                    authorization_rules: Some(account(rule_set)),
+                    authorization_rules_program: Some(account(AUTH_RULES_PROGRAM_ID)),
+                    sysvar_instructions: account(sysvar::instructions::ID),
+                    token_program: account(spl_token::ID),
+                    associated_token_program: account(spl_associated_token_account::ID),
+                    token_metadata_program: account(mpl_token_metadata::ID),
+                    system_program: account(system_program::ID),
+                },
+                None,
+                &[],
+            )
+        });
+        result.unwrap();
+
+        let accounts = TransferPnft {
+            payer,
+            authority,
+            token_owner,
+            token,
+            destination_owner,
+            mint,
+            authorization_rules: Some(rule_set),
+        };
+        assert_eq!(invoked, transfer_pnft_simple(accounts, None));
+    }
+
+    #[test]
+    fn delegate_pnft_simple_matches_cpi() {
+        let [payer, authority, delegate, mint, token, rule_set] = keys();
+        let cpi_accounts = || cpi::DelegatePnft {
+            payer: account(payer),
+            authority: account(authority),
+            delegate: account(delegate),
+            mint: account(mint),
+            metadata: pnft_metadata(mint, rule_set),
+            edition: account(find_master_edition(&mint).0),
+            token: account(token),
+            token_record: account(find_token_record(&mint, &token).0),
+            authorization_rules: Some(account(rule_set)),
+            authorization_rules_program: Some(account(AUTH_RULES_PROGRAM_ID)),
+            sysvar_instructions: account(sysvar::instructions::ID),
+            token_program: account(spl_token::ID),
+            token_metadata_program: account(mpl_token_metadata::ID),
+            system_program: account(system_program::ID),
+        };
+        let accounts = DelegatePnft {
+            payer,
+            authority,
+            delegate,
+            mint,
+            token,
+            authorization_rules: Some(rule_set),
+        };
+        let role = PnftDelegateRole::Staking;
+
+        let (result, invoked) =
+            stubs::invoked(|| cpi::delegate_pnft_simple(cpi_accounts(), role, None, &[]));
+        result.unwrap();
+        assert_eq!(invoked, delegate_pnft_simple(accounts, role, None));
+
+        let (result, invoked) =
+            stubs::invoked(|| cpi::revoke_pnft_simple(cpi_accounts(), role, &[]));
+        result.unwrap();
+        assert_eq!(invoked, revoke_pnft_simple(accounts, role));
+    }
+
+    #[test]
+    fn lock_pnft_simple_matches_cpi() {
+        let [payer, authority, token_owner, token, mint, rule_set] = keys();
+        let cpi_accounts = || cpi::LockPnft {
+            payer: account(payer),
+            authority: account(authority),
+            token_owner: account(token_owner),
+            token: account(token),
+            mint: account(mint),
+            metadata: pnft_metadata(mint, rule_set),
+            edition: account(find_master_edition(&mint).0),
+            token_record: account(find_token_record(&mint, &token).0),
+            authorization_rules: Some(account(rule_set)),
+            authorization_rules_program: Some(account(AUTH_RULES_PROGRAM_ID)),
+            sysvar_instructions: account(sysvar::instructions::ID),
+            token_program: account(spl_token::ID),
+            token_metadata_program: account(mpl_token_metadata::ID),
+            system_program: account(system_program::ID),
+        };
+        let accounts = LockPnft {
+            payer,
+            authority,
+            token_owner,
+            token,
+            mint,
+            authorization_rules: Some(rule_set),
+        };
+
+        let (result, invoked) = stubs::invoked(|| cpi::lock_pnft_simple(cpi_accounts(), None, &[]));
+        result.unwrap();
+        assert_eq!(invoked, lock_pnft_simple(accounts, None));
+
+        let (result, invoked) =
+            stubs::invoked(|| cpi::unlock_pnft_simple(cpi_accounts(), None, &[]));
+        result.unwrap();
+        assert_eq!(invoked, unlock_pnft_simple(accounts, None));
+    }
+}
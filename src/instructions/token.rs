@@ -0,0 +1,361 @@
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_lang::Result;
+use spl_token::instruction::AuthorityType;
+
+pub fn transfer(
+    from: &Pubkey,
+    to: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::transfer(
+        &spl_token::ID,
+        from,
+        to,
+        authority,
+        signers,
+        amount,
+    )?])
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked(
+    from: &Pubkey,
+    mint: &Pubkey,
+    to: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+    decimals: u8,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::transfer_checked(
+        &spl_token::ID,
+        from,
+        mint,
+        to,
+        authority,
+        signers,
+        amount,
+        decimals,
+    )?])
+}
+
+pub fn mint_to(
+    mint: &Pubkey,
+    to: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::mint_to(
+        &spl_token::ID,
+        mint,
+        to,
+        authority,
+        signers,
+        amount,
+    )?])
+}
+
+pub fn mint_to_checked(
+    mint: &Pubkey,
+    to: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+    decimals: u8,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::mint_to_checked(
+        &spl_token::ID,
+        mint,
+        to,
+        authority,
+        signers,
+        amount,
+        decimals,
+    )?])
+}
+
+pub fn burn(
+    from: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::burn(
+        &spl_token::ID,
+        from,
+        mint,
+        authority,
+        signers,
+        amount,
+    )?])
+}
+
+pub fn burn_checked(
+    from: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+    decimals: u8,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::burn_checked(
+        &spl_token::ID,
+        from,
+        mint,
+        authority,
+        signers,
+        amount,
+        decimals,
+    )?])
+}
+
+pub fn approve(
+    to: &Pubkey,
+    delegate: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::approve(
+        &spl_token::ID,
+        to,
+        delegate,
+        authority,
+        signers,
+        amount,
+    )?])
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn approve_checked(
+    to: &Pubkey,
+    mint: &Pubkey,
+    delegate: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+    decimals: u8,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::approve_checked(
+        &spl_token::ID,
+        to,
+        mint,
+        delegate,
+        authority,
+        signers,
+        amount,
+        decimals,
+    )?])
+}
+
+pub fn revoke(
+    source: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::revoke(
+        &spl_token::ID,
+        source,
+        authority,
+        signers,
+    )?])
+}
+
+pub fn initialize_account(
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::initialize_account(
+        &spl_token::ID,
+        account,
+        mint,
+        authority,
+    )?])
+}
+
+pub fn initialize_account2(
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::initialize_account2(
+        &spl_token::ID,
+        account,
+        mint,
+        authority,
+    )?])
+}
+
+pub fn initialize_account3(
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::initialize_account3(
+        &spl_token::ID,
+        account,
+        mint,
+        authority,
+    )?])
+}
+
+pub fn initialize_immutable_owner(account: &Pubkey) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::initialize_immutable_owner(
+        &spl_token::ID,
+        account,
+    )?])
+}
+
+pub fn close_account(
+    account: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::close_account(
+        &spl_token::ID,
+        account,
+        destination,
+        authority,
+        signers,
+    )?])
+}
+
+pub fn freeze_account(
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::freeze_account(
+        &spl_token::ID,
+        account,
+        mint,
+        authority,
+        signers,
+    )?])
+}
+
+pub fn thaw_account(
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::thaw_account(
+        &spl_token::ID,
+        account,
+        mint,
+        authority,
+        signers,
+    )?])
+}
+
+pub fn initialize_mint(
+    mint: &Pubkey,
+    decimals: u8,
+    authority: &Pubkey,
+    freeze_authority: Option<&Pubkey>,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::initialize_mint(
+        &spl_token::ID,
+        mint,
+        authority,
+        freeze_authority,
+        decimals,
+    )?])
+}
+
+pub fn initialize_mint2(
+    mint: &Pubkey,
+    decimals: u8,
+    authority: &Pubkey,
+    freeze_authority: Option<&Pubkey>,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::initialize_mint2(
+        &spl_token::ID,
+        mint,
+        authority,
+        freeze_authority,
+        decimals,
+    )?])
+}
+
+pub fn set_authority(
+    account_or_mint: &Pubkey,
+    current_authority: &Pubkey,
+    signers: &[&Pubkey],
+    authority_type: AuthorityType,
+    new_authority: Option<&Pubkey>,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::set_authority(
+        &spl_token::ID,
+        account_or_mint,
+        new_authority,
+        authority_type,
+        current_authority,
+        signers,
+    )?])
+}
+
+pub fn sync_native(account: &Pubkey) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::sync_native(
+        &spl_token::ID,
+        account,
+    )?])
+}
+
+pub fn initialize_multisig(
+    multisig: &Pubkey,
+    signers: &[&Pubkey],
+    m: u8,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::initialize_multisig(
+        &spl_token::ID,
+        multisig,
+        signers,
+        m,
+    )?])
+}
+
+pub fn initialize_multisig2(
+    multisig: &Pubkey,
+    signers: &[&Pubkey],
+    m: u8,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::initialize_multisig2(
+        &spl_token::ID,
+        multisig,
+        signers,
+        m,
+    )?])
+}
+
+pub fn get_account_data_size(mint: &Pubkey) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::get_account_data_size(
+        &spl_token::ID,
+        mint,
+    )?])
+}
+
+pub fn amount_to_ui_amount(mint: &Pubkey, amount: u64) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::amount_to_ui_amount(
+        &spl_token::ID,
+        mint,
+        amount,
+    )?])
+}
+
+pub fn ui_amount_to_amount(mint: &Pubkey, ui_amount: &str) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token::instruction::ui_amount_to_amount(
+        &spl_token::ID,
+        mint,
+        ui_amount,
+    )?])
+}
@@ -0,0 +1,513 @@
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_lang::Result;
+use spl_token_2022::extension::transfer_fee;
+use spl_token_2022::instruction::AuthorityType;
+
+pub fn transfer(
+    token_program_id: &Pubkey,
+    from: &Pubkey,
+    to: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+) -> Result<Vec<Instruction>> {
+    #[allow(deprecated)]
+    Ok(vec![spl_token_2022::instruction::transfer(
+        token_program_id,
+        from,
+        to,
+        authority,
+        signers,
+        amount,
+    )?])
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked(
+    token_program_id: &Pubkey,
+    from: &Pubkey,
+    mint: &Pubkey,
+    to: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+    decimals: u8,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::transfer_checked(
+        token_program_id,
+        from,
+        mint,
+        to,
+        authority,
+        signers,
+        amount,
+        decimals,
+    )?])
+}
+
+pub fn mint_to(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    to: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::mint_to(
+        token_program_id,
+        mint,
+        to,
+        authority,
+        signers,
+        amount,
+    )?])
+}
+
+pub fn mint_to_checked(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    to: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+    decimals: u8,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::mint_to_checked(
+        token_program_id,
+        mint,
+        to,
+        authority,
+        signers,
+        amount,
+        decimals,
+    )?])
+}
+
+pub fn burn(
+    token_program_id: &Pubkey,
+    from: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::burn(
+        token_program_id,
+        from,
+        mint,
+        authority,
+        signers,
+        amount,
+    )?])
+}
+
+pub fn burn_checked(
+    token_program_id: &Pubkey,
+    from: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+    decimals: u8,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::burn_checked(
+        token_program_id,
+        from,
+        mint,
+        authority,
+        signers,
+        amount,
+        decimals,
+    )?])
+}
+
+pub fn approve(
+    token_program_id: &Pubkey,
+    to: &Pubkey,
+    delegate: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::approve(
+        token_program_id,
+        to,
+        delegate,
+        authority,
+        signers,
+        amount,
+    )?])
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn approve_checked(
+    token_program_id: &Pubkey,
+    to: &Pubkey,
+    mint: &Pubkey,
+    delegate: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+    decimals: u8,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::approve_checked(
+        token_program_id,
+        to,
+        mint,
+        delegate,
+        authority,
+        signers,
+        amount,
+        decimals,
+    )?])
+}
+
+pub fn revoke(
+    token_program_id: &Pubkey,
+    source: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::revoke(
+        token_program_id,
+        source,
+        authority,
+        signers,
+    )?])
+}
+
+pub fn initialize_account(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::initialize_account(
+        token_program_id,
+        account,
+        mint,
+        authority,
+    )?])
+}
+
+pub fn initialize_account2(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::initialize_account2(
+        token_program_id,
+        account,
+        mint,
+        authority,
+    )?])
+}
+
+pub fn initialize_account3(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::initialize_account3(
+        token_program_id,
+        account,
+        mint,
+        authority,
+    )?])
+}
+
+pub fn initialize_immutable_owner(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![
+        spl_token_2022::instruction::initialize_immutable_owner(token_program_id, account)?,
+    ])
+}
+
+pub fn close_account(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::close_account(
+        token_program_id,
+        account,
+        destination,
+        authority,
+        signers,
+    )?])
+}
+
+pub fn freeze_account(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::freeze_account(
+        token_program_id,
+        account,
+        mint,
+        authority,
+        signers,
+    )?])
+}
+
+pub fn thaw_account(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::thaw_account(
+        token_program_id,
+        account,
+        mint,
+        authority,
+        signers,
+    )?])
+}
+
+pub fn initialize_mint(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    decimals: u8,
+    authority: &Pubkey,
+    freeze_authority: Option<&Pubkey>,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::initialize_mint(
+        token_program_id,
+        mint,
+        authority,
+        freeze_authority,
+        decimals,
+    )?])
+}
+
+pub fn initialize_mint2(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    decimals: u8,
+    authority: &Pubkey,
+    freeze_authority: Option<&Pubkey>,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::initialize_mint2(
+        token_program_id,
+        mint,
+        authority,
+        freeze_authority,
+        decimals,
+    )?])
+}
+
+pub fn set_authority(
+    token_program_id: &Pubkey,
+    account_or_mint: &Pubkey,
+    current_authority: &Pubkey,
+    signers: &[&Pubkey],
+    authority_type: AuthorityType,
+    new_authority: Option<&Pubkey>,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::set_authority(
+        token_program_id,
+        account_or_mint,
+        new_authority,
+        authority_type,
+        current_authority,
+        signers,
+    )?])
+}
+
+pub fn sync_native(token_program_id: &Pubkey, account: &Pubkey) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::sync_native(
+        token_program_id,
+        account,
+    )?])
+}
+
+pub fn initialize_multisig(
+    token_program_id: &Pubkey,
+    multisig: &Pubkey,
+    signers: &[&Pubkey],
+    m: u8,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::initialize_multisig(
+        token_program_id,
+        multisig,
+        signers,
+        m,
+    )?])
+}
+
+pub fn initialize_multisig2(
+    token_program_id: &Pubkey,
+    multisig: &Pubkey,
+    signers: &[&Pubkey],
+    m: u8,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::initialize_multisig2(
+        token_program_id,
+        multisig,
+        signers,
+        m,
+    )?])
+}
+
+pub fn get_account_data_size(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    extension_types: &[spl_token_2022::extension::ExtensionType],
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::get_account_data_size(
+        token_program_id,
+        mint,
+        extension_types,
+    )?])
+}
+
+pub fn amount_to_ui_amount(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::amount_to_ui_amount(
+        token_program_id,
+        mint,
+        amount,
+    )?])
+}
+
+pub fn ui_amount_to_amount(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    ui_amount: &str,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![spl_token_2022::instruction::ui_amount_to_amount(
+        token_program_id,
+        mint,
+        ui_amount,
+    )?])
+}
+
+pub fn initialize_transfer_fee_config(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    transfer_fee_config_authority: Option<&Pubkey>,
+    withdraw_withheld_authority: Option<&Pubkey>,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![
+        transfer_fee::instruction::initialize_transfer_fee_config(
+            token_program_id,
+            mint,
+            transfer_fee_config_authority,
+            withdraw_withheld_authority,
+            transfer_fee_basis_points,
+            maximum_fee,
+        )?,
+    ])
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked_with_fee(
+    token_program_id: &Pubkey,
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+    decimals: u8,
+    fee: u64,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![transfer_fee::instruction::transfer_checked_with_fee(
+        token_program_id,
+        source,
+        mint,
+        destination,
+        authority,
+        signers,
+        amount,
+        decimals,
+        fee,
+    )?])
+}
+
+pub fn withdraw_withheld_tokens_from_mint(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Vec<Instruction>> {
+    Ok(vec![
+        transfer_fee::instruction::withdraw_withheld_tokens_from_mint(
+            token_program_id,
+            mint,
+            destination,
+            authority,
+            signers,
+        )?,
+    ])
+}
+
+pub fn withdraw_withheld_tokens_from_accounts(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    sources: &[&Pubkey],
+) -> Result<Vec<Instruction>> {
+    Ok(vec![
+        transfer_fee::instruction::withdraw_withheld_tokens_from_accounts(
+            token_program_id,
+            mint,
+            destination,
+            authority,
+            signers,
+            sources,
+        )?,
+    ])
+}
+
+pub fn harvest_withheld_tokens_to_mint(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    sources: &[&Pubkey],
+) -> Result<Vec<Instruction>> {
+    Ok(vec![
+        transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+            token_program_id,
+            mint,
+            sources,
+        )?,
+    ])
+}
+
+pub fn set_transfer_fee(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> Result<Vec<Instruction>> {
+    Ok(vec![transfer_fee::instruction::set_transfer_fee(
+        token_program_id,
+        mint,
+        authority,
+        signers,
+        transfer_fee_basis_points,
+        maximum_fee,
+    )?])
+}
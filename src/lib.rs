@@ -1,8 +1,22 @@
-/// SPL Token Helper Crate Modules
+//! SPL Token Helper Crate Modules
+//!
+//! This crate provides various modules to simplify working with SPL tokens on the Solana blockchain.
+//! Each module is enabled through feature flags, allowing developers to include only the necessary components
+//! in their projects. Below are the available modules and their functionalities:
+
+/// Error Module
 ///
-/// This crate provides various modules to simplify working with SPL tokens on the Solana blockchain.
-/// Each module is enabled through feature flags, allowing developers to include only the necessary components
-/// in their projects. Below are the available modules and their functionalities:
+/// This module defines `SplSimplifiedError`, the errors returned by the helpers of this crate.
+/// Composite helpers log each step before invoking it, so the last step in the transaction logs
+/// is the one that failed; the error value itself does not say which step failed. Token and
+/// Metaplex error codes can be decoded off-chain into readable variants.
+pub mod error;
+
+#[cfg(any(feature = "token", feature = "token_2022"))]
+mod common;
+
+#[cfg(all(test, feature = "token_2022"))]
+mod stubs;
 
 #[cfg(feature = "associated_token")]
 /// Associated Token Module
@@ -28,6 +42,29 @@ pub mod metadata;
 /// to interact with SPL tokens on the Solana blockchain.
 pub mod token;
 
+#[cfg(feature = "token_2022")]
+/// Token-2022 Module
+///
+/// This module mirrors the `token` CPI wrappers, but builds every instruction against the
+/// program account passed in the `CpiContext` instead of a hardcoded program id. Both the
+/// legacy Token program and Token-2022 are accepted; any other program id is rejected.
+pub mod token_2022;
+
+#[cfg(feature = "token_2022")]
+/// Token Interface Module
+///
+/// This module re-exports the program-id-generic CPI wrappers from `token_2022` together with
+/// the types needed to accept either token program in a `#[derive(Accounts)]` struct.
+pub mod token_interface;
+
+#[cfg(feature = "token_2022")]
+/// Token-2022 Extensions Module
+///
+/// This module wraps the Token-2022 extension instructions, starting with the transfer fee
+/// extension: configuring the fee on a mint, fee-aware transfers, and harvesting and
+/// withdrawing the withheld fees.
+pub mod token_2022_extensions;
+
 #[cfg(feature = "simplespl")]
 /// Simple SPL Module
 ///
@@ -35,3 +72,12 @@ pub mod token;
 /// with minimal setup. It is designed for developers who want to quickly deploy SPL tokens
 /// without dealing with the complexities of the underlying SPL token program directly.
 pub mod simplespl;
+
+/// Instructions Module
+///
+/// This module builds the same instructions as the CPI wrappers above, but from plain `Pubkey`s
+/// and without invoking anything, so clients can assemble transactions off-chain. Each builder
+/// returns the list of instructions the matching wrapper would invoke, in order. What a wrapper
+/// reads from accounts on-chain, like the rent or an edition number, is passed in instead, and
+/// the Metaplex PDAs are derived from the mints.
+pub mod instructions;
@@ -1,14 +1,27 @@
 use anchor_lang::context::CpiContext;
 use anchor_lang::error::ErrorCode;
-use anchor_lang::{system_program, Accounts, Result, ToAccountInfos};
+use anchor_lang::prelude::borsh;
+use anchor_lang::{
+    system_program, Accounts, AnchorDeserialize, AnchorSerialize, Result, ToAccountInfos,
+};
 use solana_program::account_info::AccountInfo;
 use solana_program::pubkey::Pubkey;
 use solana_program::sysvar;
+use std::io::Write;
 use std::ops::Deref;
 
+use crate::error::SplSimplifiedError;
+use mpl_token_metadata::types::{
+    Creator, DataV2, MetadataDelegateRole, TokenDelegateRole, TokenState,
+};
+
 pub use mpl_token_metadata;
 pub use mpl_token_metadata::ID;
 
+/// The token-auth-rules program, which evaluates the rule sets of programmable NFTs.
+pub const AUTH_RULES_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg");
+
 pub fn approve_collection_authority<'info>(
     ctx: CpiContext<'_, '_, '_, 'info, ApproveCollectionAuthority<'info>>,
 ) -> Result<()> {
@@ -109,6 +122,7 @@ pub fn create_metadata_accounts_v3<'info>(
     update_authority_is_signer: bool,
     collection_details: Option<mpl_token_metadata::types::CollectionDetails>,
 ) -> Result<()> {
+    validate_data_v2(&data)?;
     let ix = mpl_token_metadata::instructions::CreateMetadataAccountV3 {
         metadata: *ctx.accounts.metadata.key,
         mint: *ctx.accounts.mint.key,
@@ -143,6 +157,10 @@ pub fn update_metadata_accounts_v2<'info>(
     primary_sale_happened: Option<bool>,
     is_mutable: Option<bool>,
 ) -> Result<()> {
+    let data = data.map(normalize_data_v2);
+    if let Some(data) = &data {
+        validate_data_v2(data)?;
+    }
     let ix = mpl_token_metadata::instructions::UpdateMetadataAccountV2 {
         metadata: *ctx.accounts.metadata.key,
         update_authority: *ctx.accounts.update_authority.key,
@@ -493,7 +511,7 @@ pub fn unverify_collection<'info>(
     collection_authority_record: Option<Pubkey>,
 ) -> Result<()> {
     let ix = mpl_token_metadata::instructions::UnverifyCollection {
-        collection: *ctx.accounts.metadata.key,
+        collection: *ctx.accounts.collection.key,
         collection_authority: *ctx.accounts.collection_authority.key,
         collection_authority_record,
         collection_master_edition_account: *ctx.accounts.collection_master_edition_account.key,
@@ -514,7 +532,7 @@ pub fn unverify_sized_collection_item<'info>(
     collection_authority_record: Option<Pubkey>,
 ) -> Result<()> {
     let ix = mpl_token_metadata::instructions::UnverifySizedCollectionItem {
-        collection: *ctx.accounts.metadata.key,
+        collection: *ctx.accounts.collection.key,
         collection_authority: *ctx.accounts.collection_authority.key,
         collection_authority_record,
         collection_master_edition_account: *ctx.accounts.collection_master_edition_account.key,
@@ -531,136 +549,776 @@ pub fn unverify_sized_collection_item<'info>(
     .map_err(Into::into)
 }
 
-#[derive(Accounts)]
-pub struct ApproveCollectionAuthority<'info> {
-    pub collection_authority_record: AccountInfo<'info>,
-    pub new_collection_authority: AccountInfo<'info>,
-    pub update_authority: AccountInfo<'info>,
-    pub payer: AccountInfo<'info>,
-    pub metadata: AccountInfo<'info>,
-    pub mint: AccountInfo<'info>,
-}
+// Wrappers of the unified instructions, which support every `TokenStandard`.
+//
+// Optional accounts are passed as `Option<Pubkey>`, like in the legacy wrappers
+// above; their account infos go in the remaining accounts of the context. The
+// token-auth-rules program is added whenever `authorization_rules` is set.
 
-#[derive(Accounts)]
-pub struct BubblegumSetCollectionSize<'info> {
-    pub metadata_account: AccountInfo<'info>,
-    pub update_authority: AccountInfo<'info>,
-    pub mint: AccountInfo<'info>,
-    pub bubblegum_signer: AccountInfo<'info>,
+pub fn create_v1<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, CreateV1<'info>>,
+    master_edition: Option<Pubkey>,
+    update_authority_is_signer: bool,
+    args: mpl_token_metadata::types::CreateArgs,
+) -> Result<()> {
+    let ix = mpl_token_metadata::instructions::Create {
+        metadata: *ctx.accounts.metadata.key,
+        master_edition,
+        // The mint only signs when the program has to create it.
+        mint: (*ctx.accounts.mint.key, ctx.accounts.mint.data_is_empty()),
+        authority: *ctx.accounts.authority.key,
+        payer: *ctx.accounts.payer.key,
+        update_authority: (
+            *ctx.accounts.update_authority.key,
+            update_authority_is_signer,
+        ),
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: *ctx.accounts.spl_token_program.key,
+    }
+    .instruction(mpl_token_metadata::instructions::CreateInstructionArgs { create_args: args });
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
 }
 
-#[derive(Accounts)]
-pub struct BurnEditionNft<'info> {
-    pub metadata: AccountInfo<'info>,
-    pub owner: AccountInfo<'info>,
-    pub print_edition_mint: AccountInfo<'info>,
-    pub master_edition_mint: AccountInfo<'info>,
-    pub print_edition_token: AccountInfo<'info>,
-    pub master_edition_token: AccountInfo<'info>,
-    pub master_edition: AccountInfo<'info>,
-    pub print_edition: AccountInfo<'info>,
-    pub edition_marker: AccountInfo<'info>,
-    pub spl_token: AccountInfo<'info>,
+#[allow(clippy::too_many_arguments)]
+pub fn mint_v1<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, MintV1<'info>>,
+    token_owner: Option<Pubkey>,
+    master_edition: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    delegate_record: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    args: mpl_token_metadata::types::MintArgs,
+) -> Result<()> {
+    let ix = mpl_token_metadata::instructions::Mint {
+        token: *ctx.accounts.token.key,
+        token_owner,
+        metadata: *ctx.accounts.metadata.key,
+        master_edition,
+        token_record,
+        mint: *ctx.accounts.mint.key,
+        authority: *ctx.accounts.authority.key,
+        delegate_record,
+        payer: *ctx.accounts.payer.key,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: *ctx.accounts.spl_token_program.key,
+        spl_ata_program: spl_associated_token_account::ID,
+        authorization_rules_program: authorization_rules.map(|_| AUTH_RULES_PROGRAM_ID),
+        authorization_rules,
+    }
+    .instruction(mpl_token_metadata::instructions::MintInstructionArgs { mint_args: args });
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
 }
 
-#[derive(Accounts)]
-pub struct BurnNft<'info> {
-    pub metadata: AccountInfo<'info>,
-    pub owner: AccountInfo<'info>,
-    pub mint: AccountInfo<'info>,
-    pub token: AccountInfo<'info>,
-    pub edition: AccountInfo<'info>,
-    pub spl_token: AccountInfo<'info>,
+pub fn transfer_v1<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferV1<'info>>,
+    edition: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    destination_token_record: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    args: mpl_token_metadata::types::TransferArgs,
+) -> Result<()> {
+    let ix = mpl_token_metadata::instructions::Transfer {
+        token: *ctx.accounts.token.key,
+        token_owner: *ctx.accounts.token_owner.key,
+        destination_token: *ctx.accounts.destination_token.key,
+        destination_owner: *ctx.accounts.destination_owner.key,
+        mint: *ctx.accounts.mint.key,
+        metadata: *ctx.accounts.metadata.key,
+        edition,
+        token_record,
+        destination_token_record,
+        authority: *ctx.accounts.authority.key,
+        payer: *ctx.accounts.payer.key,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: *ctx.accounts.spl_token_program.key,
+        spl_ata_program: spl_associated_token_account::ID,
+        authorization_rules_program: authorization_rules.map(|_| AUTH_RULES_PROGRAM_ID),
+        authorization_rules,
+    }
+    .instruction(mpl_token_metadata::instructions::TransferInstructionArgs {
+        transfer_args: args,
+    });
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
 }
 
-#[derive(Accounts)]
-pub struct CreateMetadataAccountsV3<'info> {
-    pub metadata: AccountInfo<'info>,
-    pub mint: AccountInfo<'info>,
-    pub mint_authority: AccountInfo<'info>,
-    pub payer: AccountInfo<'info>,
-    pub update_authority: AccountInfo<'info>,
-    pub system_program: AccountInfo<'info>,
-    pub rent: AccountInfo<'info>,
+pub fn update_v1<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, UpdateV1<'info>>,
+    delegate_record: Option<Pubkey>,
+    token: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    args: mpl_token_metadata::types::UpdateArgs,
+) -> Result<()> {
+    let ix = mpl_token_metadata::instructions::Update {
+        authority: *ctx.accounts.authority.key,
+        delegate_record,
+        token,
+        mint: *ctx.accounts.mint.key,
+        metadata: *ctx.accounts.metadata.key,
+        edition,
+        payer: *ctx.accounts.payer.key,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        authorization_rules_program: authorization_rules.map(|_| AUTH_RULES_PROGRAM_ID),
+        authorization_rules,
+    }
+    .instruction(mpl_token_metadata::instructions::UpdateInstructionArgs { update_args: args });
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
 }
 
-#[derive(Accounts)]
-pub struct UpdateMetadataAccountsV2<'info> {
-    pub metadata: AccountInfo<'info>,
-    pub update_authority: AccountInfo<'info>,
+#[allow(clippy::too_many_arguments)]
+pub fn burn_v1<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, BurnV1<'info>>,
+    collection_metadata: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    master_edition: Option<Pubkey>,
+    master_edition_mint: Option<Pubkey>,
+    master_edition_token: Option<Pubkey>,
+    edition_marker: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    args: mpl_token_metadata::types::BurnArgs,
+) -> Result<()> {
+    let ix = mpl_token_metadata::instructions::Burn {
+        authority: *ctx.accounts.authority.key,
+        collection_metadata,
+        metadata: *ctx.accounts.metadata.key,
+        edition,
+        mint: *ctx.accounts.mint.key,
+        token: *ctx.accounts.token.key,
+        master_edition,
+        master_edition_mint,
+        master_edition_token,
+        edition_marker,
+        token_record,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: *ctx.accounts.spl_token_program.key,
+    }
+    .instruction(mpl_token_metadata::instructions::BurnInstructionArgs { burn_args: args });
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
 }
 
-#[derive(Accounts)]
-pub struct CreateMasterEditionV3<'info> {
-    pub edition: AccountInfo<'info>,
-    pub mint: AccountInfo<'info>,
-    pub update_authority: AccountInfo<'info>,
-    pub mint_authority: AccountInfo<'info>,
-    pub payer: AccountInfo<'info>,
-    pub metadata: AccountInfo<'info>,
-    pub token_program: AccountInfo<'info>,
-    pub system_program: AccountInfo<'info>,
-    pub rent: AccountInfo<'info>,
+/// Token delegates (`Transfer`, `Sale`, `Utility`, `Staking`, `Standard`,
+/// `LockedTransfer`) need `token`, which also passes the token program; metadata
+/// delegates need `delegate_record` instead.
+#[allow(clippy::too_many_arguments)]
+pub fn delegate_v1<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, DelegateV1<'info>>,
+    delegate_record: Option<Pubkey>,
+    master_edition: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    token: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    args: mpl_token_metadata::types::DelegateArgs,
+) -> Result<()> {
+    let ix = mpl_token_metadata::instructions::Delegate {
+        delegate_record,
+        delegate: *ctx.accounts.delegate.key,
+        metadata: *ctx.accounts.metadata.key,
+        master_edition,
+        token_record,
+        mint: *ctx.accounts.mint.key,
+        token,
+        authority: *ctx.accounts.authority.key,
+        payer: *ctx.accounts.payer.key,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: token.map(|_| *ctx.accounts.spl_token_program.key),
+        authorization_rules_program: authorization_rules.map(|_| AUTH_RULES_PROGRAM_ID),
+        authorization_rules,
+    }
+    .instruction(mpl_token_metadata::instructions::DelegateInstructionArgs {
+        delegate_args: args,
+    });
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
 }
 
-#[derive(Accounts)]
-pub struct MintNewEditionFromMasterEditionViaToken<'info> {
-    pub new_metadata: AccountInfo<'info>,
-    pub new_edition: AccountInfo<'info>,
-    pub master_edition: AccountInfo<'info>,
-    pub new_mint: AccountInfo<'info>,
-    pub edition_mark_pda: AccountInfo<'info>,
-    pub new_mint_authority: AccountInfo<'info>,
-    pub payer: AccountInfo<'info>,
-    pub token_account_owner: AccountInfo<'info>,
-    pub token_account: AccountInfo<'info>,
-    pub new_metadata_update_authority: AccountInfo<'info>,
-    pub metadata: AccountInfo<'info>,
-    pub token_program: AccountInfo<'info>,
-    pub system_program: AccountInfo<'info>,
-    pub rent: AccountInfo<'info>,
-    //
-    // Not actually used by the program but still needed because it's needed
-    // for the pda calculation in the helper. :/
-    //
-    // The better thing to do would be to remove this and have the instruction
-    // helper pass in the `edition_mark_pda` directly.
-    //
-    pub metadata_mint: AccountInfo<'info>,
+/// Takes the same optional accounts as `delegate_v1`.
+#[allow(clippy::too_many_arguments)]
+pub fn revoke_v1<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, RevokeV1<'info>>,
+    delegate_record: Option<Pubkey>,
+    master_edition: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    token: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    args: mpl_token_metadata::types::RevokeArgs,
+) -> Result<()> {
+    let ix = mpl_token_metadata::instructions::Revoke {
+        delegate_record,
+        delegate: *ctx.accounts.delegate.key,
+        metadata: *ctx.accounts.metadata.key,
+        master_edition,
+        token_record,
+        mint: *ctx.accounts.mint.key,
+        token,
+        authority: *ctx.accounts.authority.key,
+        payer: *ctx.accounts.payer.key,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: token.map(|_| *ctx.accounts.spl_token_program.key),
+        authorization_rules_program: authorization_rules.map(|_| AUTH_RULES_PROGRAM_ID),
+        authorization_rules,
+    }
+    .instruction(mpl_token_metadata::instructions::RevokeInstructionArgs { revoke_args: args });
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
 }
 
-#[derive(Accounts)]
-pub struct RevokeCollectionAuthority<'info> {
-    pub collection_authority_record: AccountInfo<'info>,
-    pub delegate_authority: AccountInfo<'info>,
-    pub revoke_authority: AccountInfo<'info>,
-    pub metadata: AccountInfo<'info>,
-    pub mint: AccountInfo<'info>,
+pub fn lock_v1<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, LockV1<'info>>,
+    token_owner: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    args: mpl_token_metadata::types::LockArgs,
+) -> Result<()> {
+    let ix = mpl_token_metadata::instructions::Lock {
+        authority: *ctx.accounts.authority.key,
+        token_owner,
+        token: *ctx.accounts.token.key,
+        mint: *ctx.accounts.mint.key,
+        metadata: *ctx.accounts.metadata.key,
+        edition,
+        token_record,
+        payer: *ctx.accounts.payer.key,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: Some(*ctx.accounts.spl_token_program.key),
+        authorization_rules_program: authorization_rules.map(|_| AUTH_RULES_PROGRAM_ID),
+        authorization_rules,
+    }
+    .instruction(mpl_token_metadata::instructions::LockInstructionArgs { lock_args: args });
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
 }
 
-#[derive(Accounts)]
-pub struct SetCollectionSize<'info> {
-    pub metadata: AccountInfo<'info>,
-    pub mint: AccountInfo<'info>,
-    pub update_authority: AccountInfo<'info>,
-    pub system_program: AccountInfo<'info>,
+pub fn unlock_v1<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, UnlockV1<'info>>,
+    token_owner: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    token_record: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    args: mpl_token_metadata::types::UnlockArgs,
+) -> Result<()> {
+    let ix = mpl_token_metadata::instructions::Unlock {
+        authority: *ctx.accounts.authority.key,
+        token_owner,
+        token: *ctx.accounts.token.key,
+        mint: *ctx.accounts.mint.key,
+        metadata: *ctx.accounts.metadata.key,
+        edition,
+        token_record,
+        payer: *ctx.accounts.payer.key,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: Some(*ctx.accounts.spl_token_program.key),
+        authorization_rules_program: authorization_rules.map(|_| AUTH_RULES_PROGRAM_ID),
+        authorization_rules,
+    }
+    .instruction(mpl_token_metadata::instructions::UnlockInstructionArgs { unlock_args: args });
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
 }
 
-#[derive(Accounts)]
-pub struct SetTokenStandard<'info> {
-    pub metadata_account: AccountInfo<'info>,
-    pub update_authority: AccountInfo<'info>,
-    pub mint_account: AccountInfo<'info>,
+/// Verifies a creator (no collection accounts) or a collection item.
+pub fn verify_v1<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, VerifyV1<'info>>,
+    delegate_record: Option<Pubkey>,
+    collection_mint: Option<Pubkey>,
+    collection_metadata: Option<Pubkey>,
+    collection_master_edition: Option<Pubkey>,
+    args: mpl_token_metadata::types::VerificationArgs,
+) -> Result<()> {
+    let ix = mpl_token_metadata::instructions::Verify {
+        authority: *ctx.accounts.authority.key,
+        delegate_record,
+        metadata: *ctx.accounts.metadata.key,
+        collection_mint,
+        collection_metadata,
+        collection_master_edition,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+    }
+    .instruction(mpl_token_metadata::instructions::VerifyInstructionArgs {
+        verification_args: args,
+    });
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
 }
 
-#[derive(Accounts)]
-pub struct VerifyCollection<'info> {
-    pub payer: AccountInfo<'info>,
-    pub metadata: AccountInfo<'info>,
-    pub collection_authority: AccountInfo<'info>,
-    pub collection_mint: AccountInfo<'info>,
-    pub collection_metadata: AccountInfo<'info>,
-    pub collection_master_edition: AccountInfo<'info>,
+pub fn unverify_v1<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, UnverifyV1<'info>>,
+    delegate_record: Option<Pubkey>,
+    collection_mint: Option<Pubkey>,
+    collection_metadata: Option<Pubkey>,
+    args: mpl_token_metadata::types::VerificationArgs,
+) -> Result<()> {
+    let ix = mpl_token_metadata::instructions::Unverify {
+        authority: *ctx.accounts.authority.key,
+        delegate_record,
+        metadata: *ctx.accounts.metadata.key,
+        collection_mint,
+        collection_metadata,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+    }
+    .instruction(mpl_token_metadata::instructions::UnverifyInstructionArgs {
+        verification_args: args,
+    });
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+pub fn print_v1<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, PrintV1<'info>>,
+    edition_token_record: Option<Pubkey>,
+    args: mpl_token_metadata::types::PrintArgs,
+) -> Result<()> {
+    let ix = mpl_token_metadata::instructions::Print {
+        edition_metadata: *ctx.accounts.edition_metadata.key,
+        edition: *ctx.accounts.edition.key,
+        edition_mint: *ctx.accounts.edition_mint.key,
+        edition_token_account_owner: *ctx.accounts.edition_token_account_owner.key,
+        edition_token_account: *ctx.accounts.edition_token_account.key,
+        edition_mint_authority: *ctx.accounts.edition_mint_authority.key,
+        edition_token_record,
+        master_edition: *ctx.accounts.master_edition.key,
+        edition_marker_pda: *ctx.accounts.edition_marker.key,
+        payer: *ctx.accounts.payer.key,
+        master_token_account_owner: *ctx.accounts.master_token_account_owner.key,
+        master_token_account: *ctx.accounts.master_token_account.key,
+        master_metadata: *ctx.accounts.master_metadata.key,
+        update_authority: *ctx.accounts.update_authority.key,
+        spl_token_program: *ctx.accounts.spl_token_program.key,
+        spl_ata_program: spl_associated_token_account::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        system_program: system_program::ID,
+    }
+    .instruction(mpl_token_metadata::instructions::PrintInstructionArgs { print_args: args });
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+pub fn use_v1<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, UseV1<'info>>,
+    delegate_record: Option<Pubkey>,
+    token: Option<Pubkey>,
+    edition: Option<Pubkey>,
+    authorization_rules: Option<Pubkey>,
+    args: mpl_token_metadata::types::UseArgs,
+) -> Result<()> {
+    let ix = mpl_token_metadata::instructions::Use {
+        authority: *ctx.accounts.authority.key,
+        delegate_record,
+        token,
+        mint: *ctx.accounts.mint.key,
+        metadata: *ctx.accounts.metadata.key,
+        edition,
+        payer: *ctx.accounts.payer.key,
+        system_program: system_program::ID,
+        sysvar_instructions: sysvar::instructions::ID,
+        spl_token_program: token.map(|_| *ctx.accounts.spl_token_program.key),
+        authorization_rules_program: authorization_rules.map(|_| AUTH_RULES_PROGRAM_ID),
+        authorization_rules,
+    }
+    .instruction(mpl_token_metadata::instructions::UseInstructionArgs { use_args: args });
+    solana_program::program::invoke_signed(
+        &ix,
+        &ToAccountInfos::to_account_infos(&ctx),
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+/// Strips the trailing `\0` padding from the name, symbol and URI, so data copied
+/// from an older metadata account is written back unpadded and compares equal to
+/// plain strings. `update_metadata_accounts_v2` applies it to `data` before
+/// validating, so it only needs calling directly to compare or inspect the values.
+pub fn normalize_data_v2(mut data: DataV2) -> DataV2 {
+    for field in [&mut data.name, &mut data.symbol, &mut data.uri] {
+        let len = trim_padding(field).len();
+        field.truncate(len);
+    }
+    data
+}
+
+fn trim_padding(value: &str) -> &str {
+    value.trim_end_matches('\0')
+}
+
+/// Checks `data` against the limits the Token Metadata program enforces, so a bad
+/// name or creator list fails with a specific `SplSimplifiedError` before the CPI
+/// instead of an opaque program error. Runs in `create_metadata_accounts_v3` and
+/// `update_metadata_accounts_v2`, and can be called off-chain as well.
+///
+/// Lengths are counted in bytes, like the program does.
+pub fn validate_data_v2(data: &DataV2) -> Result<()> {
+    validate_data(
+        &data.name,
+        &data.symbol,
+        &data.uri,
+        data.seller_fee_basis_points,
+        data.creators.as_deref(),
+    )
+}
+
+pub(crate) fn validate_data(
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    seller_fee_basis_points: u16,
+    creators: Option<&[Creator]>,
+) -> Result<()> {
+    if name.len() > mpl_token_metadata::MAX_NAME_LENGTH {
+        return Err(SplSimplifiedError::NameTooLong.into());
+    }
+    if symbol.len() > mpl_token_metadata::MAX_SYMBOL_LENGTH {
+        return Err(SplSimplifiedError::SymbolTooLong.into());
+    }
+    if uri.len() > mpl_token_metadata::MAX_URI_LENGTH {
+        return Err(SplSimplifiedError::UriTooLong.into());
+    }
+    if seller_fee_basis_points > 10000 {
+        return Err(SplSimplifiedError::InvalidBasisPoints.into());
+    }
+
+    let Some(creators) = creators else {
+        return Ok(());
+    };
+    if creators.is_empty() {
+        return Err(SplSimplifiedError::CreatorsMustBeAtLeastOne.into());
+    }
+    if creators.len() > mpl_token_metadata::MAX_CREATOR_LIMIT {
+        return Err(SplSimplifiedError::CreatorsTooLong.into());
+    }
+    let mut total_share: u16 = 0;
+    for (i, creator) in creators.iter().enumerate() {
+        if creators[..i].iter().any(|c| c.address == creator.address) {
+            return Err(SplSimplifiedError::DuplicateCreatorAddress.into());
+        }
+        total_share += u16::from(creator.share);
+    }
+    if total_share != 100 {
+        return Err(SplSimplifiedError::ShareTotalMustBe100.into());
+    }
+    Ok(())
+}
+
+/// Derives the metadata PDA of `mint`.
+pub fn find_metadata(mint: &Pubkey) -> (Pubkey, u8) {
+    mpl_token_metadata::accounts::Metadata::find_pda(mint)
+}
+
+/// Derives the master edition PDA of `mint`.
+pub fn find_master_edition(mint: &Pubkey) -> (Pubkey, u8) {
+    mpl_token_metadata::accounts::MasterEdition::find_pda(mint)
+}
+
+/// Derives the edition PDA of a print's `mint`. Prints and master editions share
+/// the same seeds, so this is the same address as `find_master_edition`.
+pub fn find_edition(mint: &Pubkey) -> (Pubkey, u8) {
+    mpl_token_metadata::accounts::MasterEdition::find_pda(mint)
+}
+
+/// Derives the edition marker PDA tracking print `edition` of the master edition
+/// of `mint`. Each marker covers 248 editions.
+pub fn find_edition_marker(mint: &Pubkey, edition: u64) -> (Pubkey, u8) {
+    let marker = edition / mpl_token_metadata::EDITION_MARKER_BIT_SIZE;
+    mpl_token_metadata::accounts::EditionMarker::find_pda(mint, &marker.to_string())
+}
+
+/// Derives the token record PDA of the programmable NFT `mint` held in `token`.
+pub fn find_token_record(mint: &Pubkey, token: &Pubkey) -> (Pubkey, u8) {
+    mpl_token_metadata::accounts::TokenRecord::find_pda(mint, token)
+}
+
+/// Derives the collection authority record PDA of `collection_authority` for `mint`.
+pub fn find_collection_authority_record(
+    mint: &Pubkey,
+    collection_authority: &Pubkey,
+) -> (Pubkey, u8) {
+    mpl_token_metadata::accounts::CollectionAuthorityRecord::find_pda(mint, collection_authority)
+}
+
+/// Derives the use authority record PDA of `use_authority` for `mint`.
+pub fn find_use_authority_record(mint: &Pubkey, use_authority: &Pubkey) -> (Pubkey, u8) {
+    mpl_token_metadata::accounts::UseAuthorityRecord::find_pda(mint, use_authority)
+}
+
+/// Derives the metadata delegate record PDA of `delegate` for `mint`, created by
+/// `update_authority` for `delegate_role`.
+pub fn find_metadata_delegate_record(
+    mint: &Pubkey,
+    delegate_role: MetadataDelegateRole,
+    update_authority: &Pubkey,
+    delegate: &Pubkey,
+) -> (Pubkey, u8) {
+    mpl_token_metadata::accounts::MetadataDelegateRecord::find_pda(
+        mint,
+        delegate_role,
+        update_authority,
+        delegate,
+    )
+}
+
+pub fn assert_metadata(account: &AccountInfo, mint: &Pubkey) -> Result<()> {
+    assert_pda(account, find_metadata(mint), "metadata")
+}
+
+pub fn assert_master_edition(account: &AccountInfo, mint: &Pubkey) -> Result<()> {
+    assert_pda(account, find_master_edition(mint), "master_edition")
+}
+
+pub fn assert_edition(account: &AccountInfo, mint: &Pubkey) -> Result<()> {
+    assert_pda(account, find_edition(mint), "edition")
+}
+
+pub fn assert_edition_marker(account: &AccountInfo, mint: &Pubkey, edition: u64) -> Result<()> {
+    assert_pda(
+        account,
+        find_edition_marker(mint, edition),
+        "edition_marker",
+    )
+}
+
+pub fn assert_token_record(account: &AccountInfo, mint: &Pubkey, token: &Pubkey) -> Result<()> {
+    assert_pda(account, find_token_record(mint, token), "token_record")
+}
+
+pub fn assert_collection_authority_record(
+    account: &AccountInfo,
+    mint: &Pubkey,
+    collection_authority: &Pubkey,
+) -> Result<()> {
+    assert_pda(
+        account,
+        find_collection_authority_record(mint, collection_authority),
+        "collection_authority_record",
+    )
+}
+
+pub fn assert_use_authority_record(
+    account: &AccountInfo,
+    mint: &Pubkey,
+    use_authority: &Pubkey,
+) -> Result<()> {
+    assert_pda(
+        account,
+        find_use_authority_record(mint, use_authority),
+        "use_authority_record",
+    )
+}
+
+pub fn assert_metadata_delegate_record(
+    account: &AccountInfo,
+    mint: &Pubkey,
+    delegate_role: MetadataDelegateRole,
+    update_authority: &Pubkey,
+    delegate: &Pubkey,
+) -> Result<()> {
+    assert_pda(
+        account,
+        find_metadata_delegate_record(mint, delegate_role, update_authority, delegate),
+        "metadata_delegate_record",
+    )
+}
+
+fn assert_pda(account: &AccountInfo, (expected, _): (Pubkey, u8), name: &str) -> Result<()> {
+    if *account.key != expected {
+        solana_program::msg!("{} must be {}", name, expected);
+        return Err(SplSimplifiedError::PdaMismatch.into());
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveCollectionAuthority<'info> {
+    pub collection_authority_record: AccountInfo<'info>,
+    pub new_collection_authority: AccountInfo<'info>,
+    pub update_authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BubblegumSetCollectionSize<'info> {
+    pub metadata_account: AccountInfo<'info>,
+    pub update_authority: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub bubblegum_signer: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BurnEditionNft<'info> {
+    pub metadata: AccountInfo<'info>,
+    pub owner: AccountInfo<'info>,
+    pub print_edition_mint: AccountInfo<'info>,
+    pub master_edition_mint: AccountInfo<'info>,
+    pub print_edition_token: AccountInfo<'info>,
+    pub master_edition_token: AccountInfo<'info>,
+    pub master_edition: AccountInfo<'info>,
+    pub print_edition: AccountInfo<'info>,
+    pub edition_marker: AccountInfo<'info>,
+    pub spl_token: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BurnNft<'info> {
+    pub metadata: AccountInfo<'info>,
+    pub owner: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub token: AccountInfo<'info>,
+    pub edition: AccountInfo<'info>,
+    pub spl_token: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateMetadataAccountsV3<'info> {
+    pub metadata: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub mint_authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub update_authority: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMetadataAccountsV2<'info> {
+    pub metadata: AccountInfo<'info>,
+    pub update_authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateMasterEditionV3<'info> {
+    pub edition: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub update_authority: AccountInfo<'info>,
+    pub mint_authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MintNewEditionFromMasterEditionViaToken<'info> {
+    pub new_metadata: AccountInfo<'info>,
+    pub new_edition: AccountInfo<'info>,
+    pub master_edition: AccountInfo<'info>,
+    pub new_mint: AccountInfo<'info>,
+    pub edition_mark_pda: AccountInfo<'info>,
+    pub new_mint_authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub token_account_owner: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub new_metadata_update_authority: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+    //
+    // Not actually used by the program but still needed because it's needed
+    // for the pda calculation in the helper. :/
+    //
+    // The better thing to do would be to remove this and have the instruction
+    // helper pass in the `edition_mark_pda` directly.
+    //
+    pub metadata_mint: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeCollectionAuthority<'info> {
+    pub collection_authority_record: AccountInfo<'info>,
+    pub delegate_authority: AccountInfo<'info>,
+    pub revoke_authority: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetCollectionSize<'info> {
+    pub metadata: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub update_authority: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetTokenStandard<'info> {
+    pub metadata_account: AccountInfo<'info>,
+    pub update_authority: AccountInfo<'info>,
+    pub mint_account: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyCollection<'info> {
+    pub payer: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub collection_authority: AccountInfo<'info>,
+    pub collection_mint: AccountInfo<'info>,
+    pub collection_metadata: AccountInfo<'info>,
+    pub collection_master_edition: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
@@ -762,25 +1420,247 @@ pub struct UnverifySizedCollectionItem<'info> {
     pub collection_master_edition_account: AccountInfo<'info>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct MetadataAccount(mpl_token_metadata::accounts::Metadata);
-
-impl anchor_lang::AccountDeserialize for MetadataAccount {
-    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
-        let md = Self::try_deserialize_unchecked(buf)?;
-        if md.key != mpl_token_metadata::types::Key::MetadataV1 {
-            return Err(ErrorCode::AccountNotInitialized.into());
-        }
-        Ok(md)
-    }
-
-    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
-        let md = mpl_token_metadata::accounts::Metadata::safe_deserialize(buf)?;
-        Ok(Self(md))
-    }
+#[derive(Accounts)]
+pub struct CreateV1<'info> {
+    pub metadata: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub update_authority: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub spl_token_program: AccountInfo<'info>,
 }
 
-impl anchor_lang::AccountSerialize for MetadataAccount {}
+#[derive(Accounts)]
+pub struct MintV1<'info> {
+    pub token: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub spl_token_program: AccountInfo<'info>,
+    pub spl_ata_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferV1<'info> {
+    pub token: AccountInfo<'info>,
+    pub token_owner: AccountInfo<'info>,
+    pub destination_token: AccountInfo<'info>,
+    pub destination_owner: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub spl_token_program: AccountInfo<'info>,
+    pub spl_ata_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateV1<'info> {
+    pub authority: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BurnV1<'info> {
+    pub authority: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub token: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub spl_token_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DelegateV1<'info> {
+    pub delegate: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub spl_token_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeV1<'info> {
+    pub delegate: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub spl_token_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct LockV1<'info> {
+    pub authority: AccountInfo<'info>,
+    pub token: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub spl_token_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UnlockV1<'info> {
+    pub authority: AccountInfo<'info>,
+    pub token: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub spl_token_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyV1<'info> {
+    pub authority: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UnverifyV1<'info> {
+    pub authority: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PrintV1<'info> {
+    pub edition_metadata: AccountInfo<'info>,
+    pub edition: AccountInfo<'info>,
+    pub edition_mint: AccountInfo<'info>,
+    pub edition_token_account_owner: AccountInfo<'info>,
+    pub edition_token_account: AccountInfo<'info>,
+    pub edition_mint_authority: AccountInfo<'info>,
+    pub master_edition: AccountInfo<'info>,
+    pub edition_marker: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub master_token_account_owner: AccountInfo<'info>,
+    pub master_token_account: AccountInfo<'info>,
+    pub master_metadata: AccountInfo<'info>,
+    pub update_authority: AccountInfo<'info>,
+    pub spl_token_program: AccountInfo<'info>,
+    pub spl_ata_program: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UseV1<'info> {
+    pub authority: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub spl_token_program: AccountInfo<'info>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetadataAccount(mpl_token_metadata::accounts::Metadata);
+
+impl MetadataAccount {
+    pub fn new(account: mpl_token_metadata::accounts::Metadata) -> Self {
+        Self(account)
+    }
+
+    pub fn into_inner(self) -> mpl_token_metadata::accounts::Metadata {
+        self.0
+    }
+
+    /// The name without the `\0` padding older accounts are stored with.
+    pub fn name(&self) -> &str {
+        trim_padding(&self.0.name)
+    }
+
+    /// The symbol without the `\0` padding older accounts are stored with.
+    pub fn symbol(&self) -> &str {
+        trim_padding(&self.0.symbol)
+    }
+
+    /// The URI without the `\0` padding older accounts are stored with.
+    pub fn uri(&self) -> &str {
+        trim_padding(&self.0.uri)
+    }
+
+    /// Compares the name, ignoring padding on both sides.
+    pub fn name_eq(&self, name: &str) -> bool {
+        self.name() == trim_padding(name)
+    }
+
+    pub fn symbol_eq(&self, symbol: &str) -> bool {
+        self.symbol() == trim_padding(symbol)
+    }
+
+    pub fn uri_eq(&self, uri: &str) -> bool {
+        self.uri() == trim_padding(uri)
+    }
+
+    /// The metadata as a normalized `DataV2`, ready to be modified and passed to
+    /// `update_metadata_accounts_v2`.
+    pub fn to_data_v2(&self) -> DataV2 {
+        normalize_data_v2(DataV2 {
+            name: self.0.name.clone(),
+            symbol: self.0.symbol.clone(),
+            uri: self.0.uri.clone(),
+            seller_fee_basis_points: self.0.seller_fee_basis_points,
+            creators: self.0.creators.clone(),
+            collection: self.0.collection.clone(),
+            uses: self.0.uses.clone(),
+        })
+    }
+}
+
+impl From<mpl_token_metadata::accounts::Metadata> for MetadataAccount {
+    fn from(account: mpl_token_metadata::accounts::Metadata) -> Self {
+        Self(account)
+    }
+}
+
+impl anchor_lang::AccountDeserialize for MetadataAccount {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let md = Self::try_deserialize_unchecked(buf)?;
+        if md.key != mpl_token_metadata::types::Key::MetadataV1 {
+            return Err(ErrorCode::AccountNotInitialized.into());
+        }
+        Ok(md)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let md = mpl_token_metadata::accounts::Metadata::safe_deserialize(buf)?;
+        Ok(Self(md))
+    }
+}
+
+impl anchor_lang::AccountSerialize for MetadataAccount {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
+        self.0
+            .serialize(writer)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+    }
+}
 
 impl anchor_lang::Owner for MetadataAccount {
     fn owner() -> Pubkey {
@@ -801,6 +1681,22 @@ impl anchor_lang::IdlBuild for MetadataAccount {}
 #[derive(Clone, Debug, PartialEq)]
 pub struct MasterEditionAccount(mpl_token_metadata::accounts::MasterEdition);
 
+impl MasterEditionAccount {
+    pub fn new(account: mpl_token_metadata::accounts::MasterEdition) -> Self {
+        Self(account)
+    }
+
+    pub fn into_inner(self) -> mpl_token_metadata::accounts::MasterEdition {
+        self.0
+    }
+}
+
+impl From<mpl_token_metadata::accounts::MasterEdition> for MasterEditionAccount {
+    fn from(account: mpl_token_metadata::accounts::MasterEdition) -> Self {
+        Self(account)
+    }
+}
+
 impl anchor_lang::AccountDeserialize for MasterEditionAccount {
     fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
         let me = Self::try_deserialize_unchecked(buf)?;
@@ -823,7 +1719,13 @@ impl Deref for MasterEditionAccount {
     }
 }
 
-impl anchor_lang::AccountSerialize for MasterEditionAccount {}
+impl anchor_lang::AccountSerialize for MasterEditionAccount {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
+        self.0
+            .serialize(writer)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+    }
+}
 
 impl anchor_lang::Owner for MasterEditionAccount {
     fn owner() -> Pubkey {
@@ -839,7 +1741,63 @@ pub struct TokenRecordAccount(mpl_token_metadata::accounts::TokenRecord);
 
 impl TokenRecordAccount {
     pub const LEN: usize = mpl_token_metadata::accounts::TokenRecord::LEN;
+
+    pub fn new(account: mpl_token_metadata::accounts::TokenRecord) -> Self {
+        Self(account)
+    }
+
+    pub fn into_inner(self) -> mpl_token_metadata::accounts::TokenRecord {
+        self.0
+    }
+
+    /// Whether a delegate locked the token, e.g. while it is staked.
+    pub fn is_locked(&self) -> bool {
+        self.state == TokenState::Locked
+    }
+
+    /// Whether the token is listed by a sale delegate.
+    pub fn is_listed(&self) -> bool {
+        self.state == TokenState::Listed
+    }
+
+    /// The role of the current token delegate, if there is one.
+    pub fn delegate_role(&self) -> Option<TokenDelegateRole> {
+        self.delegate.and_then(|_| self.delegate_role.clone())
+    }
+
+    /// The only address a locked transfer delegate can transfer the token to.
+    pub fn locked_transfer(&self) -> Option<Pubkey> {
+        self.locked_transfer
+    }
+
+    /// The revision of the rule set the delegate was approved under.
+    pub fn rule_set_revision(&self) -> Option<u64> {
+        self.rule_set_revision
+    }
+
+    /// Whether `delegate` is the current token delegate.
+    pub fn is_delegate(&self, delegate: &Pubkey) -> bool {
+        self.delegate.as_ref() == Some(delegate)
+    }
+
+    /// Checks that `address`, the account this record was read from, is the token
+    /// record PDA of `mint` and `token`, and that the stored bump is the PDA bump.
+    pub fn assert_matches(&self, address: &Pubkey, mint: &Pubkey, token: &Pubkey) -> Result<()> {
+        let (expected, bump) = find_token_record(mint, token);
+        if *address != expected || self.bump != bump {
+            solana_program::msg!("token_record must be {}", expected);
+            return Err(SplSimplifiedError::PdaMismatch.into());
+        }
+        Ok(())
+    }
+}
+
+impl From<mpl_token_metadata::accounts::TokenRecord> for TokenRecordAccount {
+    fn from(account: mpl_token_metadata::accounts::TokenRecord) -> Self {
+        Self(account)
+    }
 }
+
 impl anchor_lang::AccountDeserialize for TokenRecordAccount {
     fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
         let tr = Self::try_deserialize_unchecked(buf)?;
@@ -855,7 +1813,20 @@ impl anchor_lang::AccountDeserialize for TokenRecordAccount {
     }
 }
 
-impl anchor_lang::AccountSerialize for TokenRecordAccount {}
+impl anchor_lang::AccountSerialize for TokenRecordAccount {
+    // Token records are read back by length, so the serialized record is
+    // zero-padded to the full account size.
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
+        let mut data = self
+            .0
+            .try_to_vec()
+            .map_err(|_| ErrorCode::AccountDidNotSerialize)?;
+        data.resize(Self::LEN, 0);
+        writer
+            .write_all(&data)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+    }
+}
 
 impl anchor_lang::Owner for TokenRecordAccount {
     fn owner() -> Pubkey {
@@ -873,6 +1844,440 @@ impl Deref for TokenRecordAccount {
 #[cfg(feature = "idl-build")]
 impl anchor_lang::IdlBuild for TokenRecordAccount {}
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct EditionAccount(mpl_token_metadata::accounts::Edition);
+
+impl EditionAccount {
+    pub fn new(account: mpl_token_metadata::accounts::Edition) -> Self {
+        Self(account)
+    }
+
+    pub fn into_inner(self) -> mpl_token_metadata::accounts::Edition {
+        self.0
+    }
+}
+
+impl From<mpl_token_metadata::accounts::Edition> for EditionAccount {
+    fn from(account: mpl_token_metadata::accounts::Edition) -> Self {
+        Self(account)
+    }
+}
+
+impl anchor_lang::AccountDeserialize for EditionAccount {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let account = Self::try_deserialize_unchecked(buf)?;
+        if account.key != mpl_token_metadata::types::Key::EditionV1 {
+            return Err(ErrorCode::AccountNotInitialized.into());
+        }
+        Ok(account)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let account = mpl_token_metadata::accounts::Edition::from_bytes(buf)?;
+        Ok(Self(account))
+    }
+}
+
+impl anchor_lang::AccountSerialize for EditionAccount {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
+        self.0
+            .serialize(writer)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+    }
+}
+
+impl anchor_lang::Owner for EditionAccount {
+    fn owner() -> Pubkey {
+        ID
+    }
+}
+
+impl Deref for EditionAccount {
+    type Target = mpl_token_metadata::accounts::Edition;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::IdlBuild for EditionAccount {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EditionMarkerAccount(mpl_token_metadata::accounts::EditionMarker);
+
+impl EditionMarkerAccount {
+    pub fn new(account: mpl_token_metadata::accounts::EditionMarker) -> Self {
+        Self(account)
+    }
+
+    pub fn into_inner(self) -> mpl_token_metadata::accounts::EditionMarker {
+        self.0
+    }
+}
+
+impl From<mpl_token_metadata::accounts::EditionMarker> for EditionMarkerAccount {
+    fn from(account: mpl_token_metadata::accounts::EditionMarker) -> Self {
+        Self(account)
+    }
+}
+
+impl anchor_lang::AccountDeserialize for EditionMarkerAccount {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let account = Self::try_deserialize_unchecked(buf)?;
+        if account.key != mpl_token_metadata::types::Key::EditionMarker {
+            return Err(ErrorCode::AccountNotInitialized.into());
+        }
+        Ok(account)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let account = mpl_token_metadata::accounts::EditionMarker::from_bytes(buf)?;
+        Ok(Self(account))
+    }
+}
+
+impl anchor_lang::AccountSerialize for EditionMarkerAccount {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
+        self.0
+            .serialize(writer)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+    }
+}
+
+impl anchor_lang::Owner for EditionMarkerAccount {
+    fn owner() -> Pubkey {
+        ID
+    }
+}
+
+impl Deref for EditionMarkerAccount {
+    type Target = mpl_token_metadata::accounts::EditionMarker;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::IdlBuild for EditionMarkerAccount {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EditionMarkerV2Account(mpl_token_metadata::accounts::EditionMarkerV2);
+
+impl EditionMarkerV2Account {
+    pub fn new(account: mpl_token_metadata::accounts::EditionMarkerV2) -> Self {
+        Self(account)
+    }
+
+    pub fn into_inner(self) -> mpl_token_metadata::accounts::EditionMarkerV2 {
+        self.0
+    }
+}
+
+impl From<mpl_token_metadata::accounts::EditionMarkerV2> for EditionMarkerV2Account {
+    fn from(account: mpl_token_metadata::accounts::EditionMarkerV2) -> Self {
+        Self(account)
+    }
+}
+
+impl anchor_lang::AccountDeserialize for EditionMarkerV2Account {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let account = Self::try_deserialize_unchecked(buf)?;
+        if account.key != mpl_token_metadata::types::Key::EditionMarkerV2 {
+            return Err(ErrorCode::AccountNotInitialized.into());
+        }
+        Ok(account)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let account = mpl_token_metadata::accounts::EditionMarkerV2::from_bytes(buf)?;
+        Ok(Self(account))
+    }
+}
+
+impl anchor_lang::AccountSerialize for EditionMarkerV2Account {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
+        self.0
+            .serialize(writer)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+    }
+}
+
+impl anchor_lang::Owner for EditionMarkerV2Account {
+    fn owner() -> Pubkey {
+        ID
+    }
+}
+
+impl Deref for EditionMarkerV2Account {
+    type Target = mpl_token_metadata::accounts::EditionMarkerV2;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::IdlBuild for EditionMarkerV2Account {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CollectionAuthorityRecordAccount(
+    mpl_token_metadata::accounts::CollectionAuthorityRecord,
+);
+
+impl CollectionAuthorityRecordAccount {
+    pub fn new(account: mpl_token_metadata::accounts::CollectionAuthorityRecord) -> Self {
+        Self(account)
+    }
+
+    pub fn into_inner(self) -> mpl_token_metadata::accounts::CollectionAuthorityRecord {
+        self.0
+    }
+}
+
+impl From<mpl_token_metadata::accounts::CollectionAuthorityRecord>
+    for CollectionAuthorityRecordAccount
+{
+    fn from(account: mpl_token_metadata::accounts::CollectionAuthorityRecord) -> Self {
+        Self(account)
+    }
+}
+
+impl anchor_lang::AccountDeserialize for CollectionAuthorityRecordAccount {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let account = Self::try_deserialize_unchecked(buf)?;
+        if account.key != mpl_token_metadata::types::Key::CollectionAuthorityRecord {
+            return Err(ErrorCode::AccountNotInitialized.into());
+        }
+        Ok(account)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let account = mpl_token_metadata::accounts::CollectionAuthorityRecord::from_bytes(buf)?;
+        Ok(Self(account))
+    }
+}
+
+impl anchor_lang::AccountSerialize for CollectionAuthorityRecordAccount {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
+        self.0
+            .serialize(writer)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+    }
+}
+
+impl anchor_lang::Owner for CollectionAuthorityRecordAccount {
+    fn owner() -> Pubkey {
+        ID
+    }
+}
+
+impl Deref for CollectionAuthorityRecordAccount {
+    type Target = mpl_token_metadata::accounts::CollectionAuthorityRecord;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::IdlBuild for CollectionAuthorityRecordAccount {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseAuthorityRecordAccount(mpl_token_metadata::accounts::UseAuthorityRecord);
+
+impl UseAuthorityRecordAccount {
+    pub fn new(account: mpl_token_metadata::accounts::UseAuthorityRecord) -> Self {
+        Self(account)
+    }
+
+    pub fn into_inner(self) -> mpl_token_metadata::accounts::UseAuthorityRecord {
+        self.0
+    }
+}
+
+impl From<mpl_token_metadata::accounts::UseAuthorityRecord> for UseAuthorityRecordAccount {
+    fn from(account: mpl_token_metadata::accounts::UseAuthorityRecord) -> Self {
+        Self(account)
+    }
+}
+
+impl anchor_lang::AccountDeserialize for UseAuthorityRecordAccount {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let account = Self::try_deserialize_unchecked(buf)?;
+        if account.key != mpl_token_metadata::types::Key::UseAuthorityRecord {
+            return Err(ErrorCode::AccountNotInitialized.into());
+        }
+        Ok(account)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let account = mpl_token_metadata::accounts::UseAuthorityRecord::from_bytes(buf)?;
+        Ok(Self(account))
+    }
+}
+
+impl anchor_lang::AccountSerialize for UseAuthorityRecordAccount {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
+        self.0
+            .serialize(writer)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+    }
+}
+
+impl anchor_lang::Owner for UseAuthorityRecordAccount {
+    fn owner() -> Pubkey {
+        ID
+    }
+}
+
+impl Deref for UseAuthorityRecordAccount {
+    type Target = mpl_token_metadata::accounts::UseAuthorityRecord;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::IdlBuild for UseAuthorityRecordAccount {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetadataDelegateRecordAccount(mpl_token_metadata::accounts::MetadataDelegateRecord);
+
+impl MetadataDelegateRecordAccount {
+    pub fn new(account: mpl_token_metadata::accounts::MetadataDelegateRecord) -> Self {
+        Self(account)
+    }
+
+    pub fn into_inner(self) -> mpl_token_metadata::accounts::MetadataDelegateRecord {
+        self.0
+    }
+}
+
+impl From<mpl_token_metadata::accounts::MetadataDelegateRecord> for MetadataDelegateRecordAccount {
+    fn from(account: mpl_token_metadata::accounts::MetadataDelegateRecord) -> Self {
+        Self(account)
+    }
+}
+
+impl anchor_lang::AccountDeserialize for MetadataDelegateRecordAccount {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let account = Self::try_deserialize_unchecked(buf)?;
+        if account.key != mpl_token_metadata::types::Key::MetadataDelegate {
+            return Err(ErrorCode::AccountNotInitialized.into());
+        }
+        Ok(account)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let account = mpl_token_metadata::accounts::MetadataDelegateRecord::from_bytes(buf)?;
+        Ok(Self(account))
+    }
+}
+
+impl anchor_lang::AccountSerialize for MetadataDelegateRecordAccount {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
+        self.0
+            .serialize(writer)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+    }
+}
+
+impl anchor_lang::Owner for MetadataDelegateRecordAccount {
+    fn owner() -> Pubkey {
+        ID
+    }
+}
+
+impl Deref for MetadataDelegateRecordAccount {
+    type Target = mpl_token_metadata::accounts::MetadataDelegateRecord;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::IdlBuild for MetadataDelegateRecordAccount {}
+
+/// The `Key` of a holder delegate record, `Key::HolderDelegate` in the Token
+/// Metadata program.
+///
+/// mpl-token-metadata 3.2.3 predates holder delegates: its `Key` enum ends at
+/// `EditionMarkerV2` and the account is not generated, so the record is defined
+/// here with a raw key byte.
+pub const HOLDER_DELEGATE_KEY: u8 = 14;
+
+/// The record of a holder delegate, e.g. a print delegate, with the layout of the
+/// Token Metadata program.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct HolderDelegateRecord {
+    /// [`HOLDER_DELEGATE_KEY`] once initialized.
+    pub key: u8,
+    pub bump: u8,
+    pub mint: Pubkey,
+    pub delegate: Pubkey,
+    pub update_authority: Pubkey,
+}
+
+impl HolderDelegateRecord {
+    pub const LEN: usize = 98;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HolderDelegateRecordAccount(HolderDelegateRecord);
+
+impl HolderDelegateRecordAccount {
+    pub fn new(account: HolderDelegateRecord) -> Self {
+        Self(account)
+    }
+
+    pub fn into_inner(self) -> HolderDelegateRecord {
+        self.0
+    }
+}
+
+impl From<HolderDelegateRecord> for HolderDelegateRecordAccount {
+    fn from(account: HolderDelegateRecord) -> Self {
+        Self(account)
+    }
+}
+
+impl anchor_lang::AccountDeserialize for HolderDelegateRecordAccount {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let account = Self::try_deserialize_unchecked(buf)?;
+        if account.key != HOLDER_DELEGATE_KEY {
+            return Err(ErrorCode::AccountNotInitialized.into());
+        }
+        Ok(account)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let account = HolderDelegateRecord::deserialize(buf)?;
+        Ok(Self(account))
+    }
+}
+
+impl anchor_lang::AccountSerialize for HolderDelegateRecordAccount {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
+        self.0
+            .serialize(writer)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+    }
+}
+
+impl anchor_lang::Owner for HolderDelegateRecordAccount {
+    fn owner() -> Pubkey {
+        ID
+    }
+}
+
+impl Deref for HolderDelegateRecordAccount {
+    type Target = HolderDelegateRecord;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::IdlBuild for HolderDelegateRecordAccount {}
+
 #[derive(Clone)]
 pub struct Metadata;
 
@@ -881,3 +2286,456 @@ impl anchor_lang::Id for Metadata {
         ID
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{AccountDeserialize, AccountSerialize};
+    use mpl_token_metadata::types::{Creator, Key, TokenStandard};
+
+    fn round_trip<T: AccountSerialize + AccountDeserialize>(account: &T) -> (Vec<u8>, T) {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        let back = T::try_deserialize(&mut data.as_slice()).unwrap();
+        (data, back)
+    }
+
+    fn metadata() -> mpl_token_metadata::accounts::Metadata {
+        mpl_token_metadata::accounts::Metadata {
+            key: Key::MetadataV1,
+            update_authority: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            name: "Token".to_string(),
+            symbol: "TKN".to_string(),
+            uri: "https://example.com/token.json".to_string(),
+            seller_fee_basis_points: 500,
+            creators: None,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: Some(255),
+            token_standard: Some(TokenStandard::Fungible),
+            collection: None,
+            uses: None,
+            collection_details: None,
+            programmable_config: None,
+        }
+    }
+
+    fn token_record(locked_transfer: Option<Pubkey>) -> TokenRecordAccount {
+        TokenRecordAccount::new(mpl_token_metadata::accounts::TokenRecord {
+            key: Key::TokenRecord,
+            bump: 254,
+            state: TokenState::Locked,
+            rule_set_revision: Some(3),
+            delegate: Some(Pubkey::new_unique()),
+            delegate_role: Some(TokenDelegateRole::Staking),
+            locked_transfer,
+        })
+    }
+
+    #[test]
+    fn metadata_round_trips() {
+        let metadata = MetadataAccount::new(metadata());
+        let (_, back) = round_trip(&metadata);
+        assert_eq!(back, metadata);
+    }
+
+    #[test]
+    fn master_edition_round_trips() {
+        let edition = MasterEditionAccount::new(mpl_token_metadata::accounts::MasterEdition {
+            key: Key::MasterEditionV2,
+            supply: 3,
+            max_supply: Some(10),
+        });
+        let (_, back) = round_trip(&edition);
+        assert_eq!(back, edition);
+    }
+
+    #[test]
+    fn token_record_is_padded_to_len() {
+        let record = token_record(None);
+        let (data, back) = round_trip(&record);
+        assert_eq!(data.len(), TokenRecordAccount::LEN);
+        assert_eq!(back, record);
+
+        let record = token_record(Some(Pubkey::new_unique()));
+        let (data, back) = round_trip(&record);
+        assert_eq!(data.len(), TokenRecordAccount::LEN);
+        assert_eq!(back, record);
+    }
+
+    // Deserializes `account` from a zeroed buffer of `len` bytes, the way the
+    // program allocates it.
+    fn from_padded<T: AccountSerialize + AccountDeserialize>(account: &T, len: usize) -> T {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= len);
+        data.resize(len, 0);
+        T::try_deserialize(&mut data.as_slice()).unwrap()
+    }
+
+    fn assert_uninitialized<T: AccountSerialize + AccountDeserialize>(account: &T) {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        assert_eq!(
+            T::try_deserialize(&mut data.as_slice()).err(),
+            Some(ErrorCode::AccountNotInitialized.into())
+        );
+    }
+
+    #[test]
+    fn edition_round_trips() {
+        let edition = |key| {
+            EditionAccount::new(mpl_token_metadata::accounts::Edition {
+                key,
+                parent: Pubkey::new_unique(),
+                edition: 7,
+            })
+        };
+        let account = edition(Key::EditionV1);
+        let (data, back) = round_trip(&account);
+        assert_eq!(data.len(), mpl_token_metadata::accounts::Edition::LEN);
+        assert_eq!(back, account);
+        // The program allocates editions with 200 spare bytes.
+        assert_eq!(from_padded(&account, 241), account);
+
+        assert_uninitialized(&edition(Key::MasterEditionV2));
+    }
+
+    #[test]
+    fn edition_marker_round_trips() {
+        let marker = |key| {
+            let mut ledger = [0; 31];
+            ledger[0] = 0b1010_0000;
+            EditionMarkerAccount::new(mpl_token_metadata::accounts::EditionMarker { key, ledger })
+        };
+        let account = marker(Key::EditionMarker);
+        let (data, back) = round_trip(&account);
+        assert_eq!(data.len(), mpl_token_metadata::MAX_EDITION_MARKER_SIZE);
+        assert_eq!(back, account);
+        assert_eq!(
+            from_padded(&account, mpl_token_metadata::MAX_EDITION_MARKER_SIZE),
+            account
+        );
+
+        assert_uninitialized(&marker(Key::EditionMarkerV2));
+    }
+
+    #[test]
+    fn edition_marker_v2_round_trips() {
+        let marker = |key| {
+            EditionMarkerV2Account::new(mpl_token_metadata::accounts::EditionMarkerV2 {
+                key,
+                ledger: vec![0b1000_0000, 0, 0b0000_0001],
+            })
+        };
+        let account = marker(Key::EditionMarkerV2);
+        let (data, back) = round_trip(&account);
+        assert_eq!(back, account);
+        // The ledger grows with the prints, the account may be larger than it.
+        assert_eq!(from_padded(&account, data.len() + 32), account);
+
+        assert_uninitialized(&marker(Key::EditionMarker));
+    }
+
+    #[test]
+    fn collection_authority_record_round_trips() {
+        let record = |key| {
+            CollectionAuthorityRecordAccount::new(
+                mpl_token_metadata::accounts::CollectionAuthorityRecord {
+                    key,
+                    bump: 254,
+                    update_authority: Some(Pubkey::new_unique()),
+                },
+            )
+        };
+        let account = record(Key::CollectionAuthorityRecord);
+        let (_, back) = round_trip(&account);
+        assert_eq!(back, account);
+
+        assert_uninitialized(&record(Key::UseAuthorityRecord));
+    }
+
+    #[test]
+    fn use_authority_record_round_trips() {
+        let record = |key| {
+            UseAuthorityRecordAccount::new(mpl_token_metadata::accounts::UseAuthorityRecord {
+                key,
+                allowed_uses: 5,
+                bump: 254,
+            })
+        };
+        let account = record(Key::UseAuthorityRecord);
+        let (data, back) = round_trip(&account);
+        assert_eq!(
+            data.len(),
+            mpl_token_metadata::accounts::UseAuthorityRecord::LEN
+        );
+        assert_eq!(back, account);
+
+        assert_uninitialized(&record(Key::CollectionAuthorityRecord));
+    }
+
+    #[test]
+    fn metadata_delegate_record_round_trips() {
+        let record = |key| {
+            MetadataDelegateRecordAccount::new(
+                mpl_token_metadata::accounts::MetadataDelegateRecord {
+                    key,
+                    bump: 254,
+                    mint: Pubkey::new_unique(),
+                    delegate: Pubkey::new_unique(),
+                    update_authority: Pubkey::new_unique(),
+                },
+            )
+        };
+        let account = record(Key::MetadataDelegate);
+        let (data, back) = round_trip(&account);
+        assert_eq!(
+            data.len(),
+            mpl_token_metadata::accounts::MetadataDelegateRecord::LEN
+        );
+        assert_eq!(back, account);
+
+        assert_uninitialized(&record(Key::TokenRecord));
+    }
+
+    #[test]
+    fn holder_delegate_record_round_trips() {
+        let record = |key| {
+            HolderDelegateRecordAccount::new(HolderDelegateRecord {
+                key,
+                bump: 254,
+                mint: Pubkey::new_unique(),
+                delegate: Pubkey::new_unique(),
+                update_authority: Pubkey::new_unique(),
+            })
+        };
+        let account = record(HOLDER_DELEGATE_KEY);
+        let (data, back) = round_trip(&account);
+        assert_eq!(data.len(), HolderDelegateRecord::LEN);
+        assert_eq!(back, account);
+
+        assert_uninitialized(&record(Key::MetadataDelegate as u8));
+    }
+
+    fn data() -> DataV2 {
+        DataV2 {
+            name: "Token".to_string(),
+            symbol: "TKN".to_string(),
+            uri: "https://example.com/token.json".to_string(),
+            seller_fee_basis_points: 500,
+            creators: None,
+            collection: None,
+            uses: None,
+        }
+    }
+
+    fn creator(share: u8) -> Creator {
+        Creator {
+            address: Pubkey::new_unique(),
+            verified: false,
+            share,
+        }
+    }
+
+    fn assert_invalid(data: DataV2, error: SplSimplifiedError) {
+        assert_eq!(validate_data_v2(&data).unwrap_err(), error.into());
+    }
+
+    #[test]
+    fn validate_accepts_data_at_the_limits() {
+        let data = DataV2 {
+            name: "n".repeat(mpl_token_metadata::MAX_NAME_LENGTH),
+            symbol: "s".repeat(mpl_token_metadata::MAX_SYMBOL_LENGTH),
+            uri: "u".repeat(mpl_token_metadata::MAX_URI_LENGTH),
+            seller_fee_basis_points: 10000,
+            creators: Some((0..5).map(|_| creator(20)).collect()),
+            ..data()
+        };
+        validate_data_v2(&data).unwrap();
+    }
+
+    #[test]
+    fn validate_rejects_long_name() {
+        let name = "n".repeat(mpl_token_metadata::MAX_NAME_LENGTH + 1);
+        assert_invalid(DataV2 { name, ..data() }, SplSimplifiedError::NameTooLong);
+    }
+
+    #[test]
+    fn validate_rejects_long_symbol() {
+        let symbol = "s".repeat(mpl_token_metadata::MAX_SYMBOL_LENGTH + 1);
+        assert_invalid(
+            DataV2 { symbol, ..data() },
+            SplSimplifiedError::SymbolTooLong,
+        );
+    }
+
+    #[test]
+    fn validate_rejects_long_uri() {
+        let uri = "u".repeat(mpl_token_metadata::MAX_URI_LENGTH + 1);
+        assert_invalid(DataV2 { uri, ..data() }, SplSimplifiedError::UriTooLong);
+    }
+
+    #[test]
+    fn validate_counts_bytes_not_chars() {
+        // "é" is two bytes, so 16 of them fill the 32 byte name exactly.
+        let name = "é".repeat(mpl_token_metadata::MAX_NAME_LENGTH / 2);
+        validate_data_v2(&DataV2 { name, ..data() }).unwrap();
+
+        let name = "é".repeat(mpl_token_metadata::MAX_NAME_LENGTH / 2 + 1);
+        assert!(name.chars().count() < mpl_token_metadata::MAX_NAME_LENGTH);
+        assert_invalid(DataV2 { name, ..data() }, SplSimplifiedError::NameTooLong);
+    }
+
+    #[test]
+    fn validate_rejects_basis_points_above_10000() {
+        let data = DataV2 {
+            seller_fee_basis_points: 10001,
+            ..data()
+        };
+        assert_invalid(data, SplSimplifiedError::InvalidBasisPoints);
+    }
+
+    #[test]
+    fn validate_rejects_empty_creators() {
+        let data = DataV2 {
+            creators: Some(Vec::new()),
+            ..data()
+        };
+        assert_invalid(data, SplSimplifiedError::CreatorsMustBeAtLeastOne);
+    }
+
+    #[test]
+    fn validate_rejects_six_creators() {
+        let mut creators: Vec<_> = (0..5).map(|_| creator(20)).collect();
+        creators.push(creator(0));
+        let data = DataV2 {
+            creators: Some(creators),
+            ..data()
+        };
+        assert_invalid(data, SplSimplifiedError::CreatorsTooLong);
+    }
+
+    #[test]
+    fn validate_rejects_duplicate_creator() {
+        let first = creator(50);
+        let duplicate = Creator {
+            share: 50,
+            ..first.clone()
+        };
+        let data = DataV2 {
+            creators: Some(vec![first, duplicate]),
+            ..data()
+        };
+        assert_invalid(data, SplSimplifiedError::DuplicateCreatorAddress);
+    }
+
+    #[test]
+    fn validate_rejects_shares_not_totalling_100() {
+        for shares in [vec![99], vec![60, 41]] {
+            let data = DataV2 {
+                creators: Some(shares.into_iter().map(creator).collect()),
+                ..data()
+            };
+            assert_invalid(data, SplSimplifiedError::ShareTotalMustBe100);
+        }
+    }
+
+    fn padded(value: &str, len: usize) -> String {
+        format!("{value:\0<len$}")
+    }
+
+    #[test]
+    fn trim_padding_strips_trailing_nulls_only() {
+        assert_eq!(trim_padding(&padded("Token", 32)), "Token");
+        assert_eq!(trim_padding("Token"), "Token");
+        assert_eq!(trim_padding("\0Token"), "\0Token");
+        assert_eq!(trim_padding("\0\0"), "");
+    }
+
+    #[test]
+    fn normalize_data_v2_trims_every_string() {
+        let padded_data = DataV2 {
+            name: padded("Token", mpl_token_metadata::MAX_NAME_LENGTH),
+            symbol: padded("TKN", mpl_token_metadata::MAX_SYMBOL_LENGTH),
+            uri: padded(
+                "https://example.com/token.json",
+                mpl_token_metadata::MAX_URI_LENGTH,
+            ),
+            ..data()
+        };
+        assert_eq!(normalize_data_v2(padded_data), data());
+    }
+
+    #[cfg(feature = "token_2022")]
+    #[test]
+    fn update_metadata_accounts_v2_writes_normalized_data() {
+        fn account(key: Pubkey) -> AccountInfo<'static> {
+            AccountInfo::new(
+                Box::leak(Box::new(key)),
+                false,
+                false,
+                Box::leak(Box::new(0)),
+                &mut [],
+                Box::leak(Box::new(Pubkey::default())),
+                false,
+                0,
+            )
+        }
+
+        let (metadata, update_authority) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let padded_data = DataV2 {
+            name: padded("Token", mpl_token_metadata::MAX_NAME_LENGTH),
+            symbol: padded("TKN", mpl_token_metadata::MAX_SYMBOL_LENGTH),
+            ..data()
+        };
+        let ctx = CpiContext::new(
+            account(mpl_token_metadata::ID),
+            UpdateMetadataAccountsV2 {
+                metadata: account(metadata),
+                update_authority: account(update_authority),
+            },
+        );
+
+        let (result, invoked) = crate::stubs::invoked(|| {
+            update_metadata_accounts_v2(ctx, None, Some(padded_data), None, None)
+        });
+
+        result.unwrap();
+        let expected = mpl_token_metadata::instructions::UpdateMetadataAccountV2 {
+            metadata,
+            update_authority,
+        }
+        .instruction(
+            mpl_token_metadata::instructions::UpdateMetadataAccountV2InstructionArgs {
+                new_update_authority: None,
+                data: Some(data()),
+                primary_sale_happened: None,
+                is_mutable: None,
+            },
+        );
+        assert_eq!(invoked, vec![expected]);
+    }
+
+    #[test]
+    fn padded_metadata_compares_trimmed() {
+        let metadata = MetadataAccount::new(mpl_token_metadata::accounts::Metadata {
+            name: padded("Token", mpl_token_metadata::MAX_NAME_LENGTH),
+            symbol: padded("TKN", mpl_token_metadata::MAX_SYMBOL_LENGTH),
+            uri: padded(
+                "https://example.com/token.json",
+                mpl_token_metadata::MAX_URI_LENGTH,
+            ),
+            ..metadata()
+        });
+
+        assert_eq!(metadata.name(), "Token");
+        assert!(metadata.name_eq("Token"));
+        assert!(metadata.name_eq(&padded("Token", 10)));
+        assert!(!metadata.name_eq("Tok"));
+        assert!(metadata.symbol_eq("TKN"));
+        assert!(metadata.uri_eq("https://example.com/token.json"));
+        assert_eq!(metadata.to_data_v2(), data());
+    }
+}
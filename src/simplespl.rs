@@ -1,129 +1,2445 @@
 use anchor_lang::context::CpiContext;
+use anchor_lang::error::{Error, ErrorCode};
 use anchor_lang::solana_program::account_info::AccountInfo;
+use anchor_lang::solana_program::program_pack::Pack;
 use anchor_lang::solana_program::pubkey::Pubkey;
-use anchor_lang::Key;
+use anchor_lang::solana_program::rent::Rent;
+use anchor_lang::solana_program::sysvar::Sysvar;
+use anchor_lang::system_program::{self, create_account, CreateAccount};
+use anchor_lang::{error, require_keys_eq, AccountDeserialize, Accounts, Key};
 use anchor_lang::{solana_program, Result};
-use mpl_token_metadata::types::{Creator, DataV2};
+use mpl_token_metadata::accounts::Metadata;
+use mpl_token_metadata::types::{
+    AuthorizationData, Collection, CollectionDetails, Creator, DataV2, DelegateArgs, LockArgs,
+    ProgrammableConfig, RevokeArgs, TokenStandard, TransferArgs, UnlockArgs, UseMethod, Uses,
+};
+use solana_program::msg;
 use solana_program::program::invoke_signed;
+use solana_program::sysvar;
 pub use spl_token::ID;
+use spl_token_2022::extension::{ExtensionType, StateWithExtensions};
+use spl_token_2022::instruction::AuthorityType;
 
-use crate::metadata::{create_metadata_accounts_v3, CreateMetadataAccountsV3};
+use crate::associated_token::{create_idempotent, Create};
+use crate::error::SplSimplifiedError;
+use crate::metadata::{
+    assert_collection_authority_record, assert_edition, assert_edition_marker,
+    assert_master_edition, assert_metadata, assert_token_record, create_master_edition_v3,
+    create_metadata_accounts_v3, delegate_v1, lock_v1,
+    mint_new_edition_from_master_edition_via_token, revoke_v1, set_and_verify_collection,
+    set_and_verify_sized_collection_item, set_collection_size, transfer_v1, unlock_v1,
+    unverify_collection, unverify_sized_collection_item, validate_data, verify_collection,
+    verify_sized_collection_item, CreateMasterEditionV3, CreateMetadataAccountsV3, DelegateV1,
+    LockV1, MasterEditionAccount, MintNewEditionFromMasterEditionViaToken, RevokeV1,
+    SetAndVerifyCollection, SetAndVerifySizedCollectionItem, SetCollectionSize, TransferV1,
+    UnlockV1, UnverifyCollection, UnverifySizedCollectionItem, VerifyCollection,
+    VerifySizedCollectionItem, AUTH_RULES_PROGRAM_ID,
+};
+use crate::token::accessor;
+use crate::token_2022::{
+    close_account, initialize_mint2, mint_to, set_authority, sync_native, transfer_checked,
+    CloseAccount, InitializeMint2, MintTo, SetAuthority, SyncNative, TransferChecked,
+};
+use crate::token_2022_extensions::{
+    calculate_transfer_fee, initialize_transfer_fee_config, transfer_checked_with_fee,
+    InitializeTransferFeeConfig, TransferCheckedWithFee,
+};
 
 /// Mints new SPL tokens with associated metadata.
 ///
+/// Prefer [`SimpleMint`], which takes the same accounts through named setters.
+///
 /// This function creates metadata for a new token using the `mpl_token_metadata`
 /// program, and mints the specified number of tokens to the `to` account.
 ///
 /// # Arguments
 ///
-/// * `token_name` - The name of the token to be minted.
-/// * `token_symbol` - The symbol for the token.
-/// * `token_uri` - A URI pointing to the token's metadata (e.g., hosted image or metadata information).
-/// * `token_tax` - The seller fee basis points (bps) for token transactions.
-/// * `payer` - The account responsible for paying transaction fees.
-/// * `token_metadata_program` - The account for the token metadata program.
-/// * `update_authority` - The account authorized to update the token's metadata.
-/// * `metadata` - The metadata account for the token.
-/// * `mint_authority` - The account authorized to mint the tokens.
-/// * `system_program` - The system program account.
-/// * `rent` - The rent sysvar account.
-/// * `token_program` - The SPL token program account.
-/// * `mint` - The token mint account.
-/// * `to` - The account where the minted tokens will be transferred to.
-/// * `owner` - The owner of the `to` account.
-/// * `signer_seeds` - The seeds used to sign the transaction.
-/// * `amount` - The number of tokens to mint.
+/// * `token_name` - The name of the token to be minted.
+/// * `token_symbol` - The symbol for the token.
+/// * `token_uri` - A URI pointing to the token's metadata (e.g., hosted image or metadata information).
+/// * `token_tax` - The seller fee basis points (bps) for token transactions.
+/// * `payer` - The account responsible for paying transaction fees.
+/// * `token_metadata_program` - The account for the token metadata program.
+/// * `update_authority` - The account authorized to update the token's metadata.
+/// * `metadata` - The metadata account for the token.
+/// * `mint_authority` - The account authorized to mint the tokens.
+/// * `system_program` - The system program account.
+/// * `rent` - The rent sysvar account.
+/// * `token_program` - The SPL token program account.
+/// * `mint` - The token mint account.
+/// * `to` - The account where the minted tokens will be transferred to.
+/// * `owner` - The owner of the `to` account.
+/// * `signer_seeds` - The seeds used to sign the transaction.
+/// * `amount` - The number of tokens to mint.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// use spl_simplified::simplespl::mint_simple;
+///
+/// # fn example<'info>(
+/// #     payer_account_info: AccountInfo<'info>,
+/// #     token_metadata_program_info: AccountInfo<'info>,
+/// #     update_authority_info: AccountInfo<'info>,
+/// #     metadata_account_info: AccountInfo<'info>,
+/// #     mint_authority_info: AccountInfo<'info>,
+/// #     system_program_info: AccountInfo<'info>,
+/// #     rent_sysvar_info: AccountInfo<'info>,
+/// #     token_program_info: AccountInfo<'info>,
+/// #     mint_account_info: AccountInfo<'info>,
+/// #     to_account_info: AccountInfo<'info>,
+/// #     owner_account_info: AccountInfo<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// let result = mint_simple(
+///     "TokenName".to_string(),
+///     "TKN".to_string(),
+///     "https://example.com/token-metadata".to_string(),
+///     500, // 5% seller fee
+///     payer_account_info,
+///     token_metadata_program_info,
+///     update_authority_info,
+///     metadata_account_info,
+///     mint_authority_info,
+///     system_program_info,
+///     rent_sysvar_info,
+///     token_program_info,
+///     mint_account_info,
+///     to_account_info,
+///     owner_account_info,
+///     signer_seeds,
+///     1000 // Mint 1000 tokens
+/// ).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn mint_simple<'info>(
+    token_name: String,
+    token_symbol: String,
+    token_uri: String,
+    token_tax: u16,
+    payer: AccountInfo<'info>,
+    token_metadata_program: AccountInfo<'info>,
+    update_authority: AccountInfo<'info>,
+    metadata: AccountInfo<'info>,
+    mint_authority: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    rent: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    owner: AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+    amount: u64,
+) -> Result<()> {
+    msg!("mint_simple: create metadata");
+    metadata_thing(
+        SimpleMetadata {
+            name: token_name,
+            symbol: token_symbol,
+            uri: token_uri,
+            seller_fee_basis_points: token_tax,
+            ..Default::default()
+        },
+        payer,
+        token_metadata_program,
+        update_authority,
+        mint.clone(),
+        metadata,
+        mint_authority,
+        system_program,
+        rent,
+        &[signer_seeds],
+    )?;
+
+    msg!("mint_simple: mint_to");
+    let ix = spl_token::instruction::mint_to(
+        &spl_token::ID,
+        &mint.key(),
+        &to.key(),
+        &owner.key(),
+        &[],
+        amount,
+    )?;
+
+    invoke_signed(
+        &ix,
+        &[mint.clone(), to.clone(), owner.clone(), token_program],
+        &[signer_seeds],
+    )
+    .map_err(Into::into)
+}
+
+/// Metadata attached to a mint by [`SimpleMint`].
+///
+/// Without `creators`, the update authority is written as the sole, verified
+/// creator. Metaplex only lets the update authority be verified while the
+/// metadata is created, so any other creator has to be unverified and sign later
+/// with `sign_metadata`.
+/// Likewise `collection` is written unverified; verify it afterwards with
+/// `metadata::verify_collection` or `metadata::verify_sized_collection_item`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SimpleMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub creators: Option<Vec<Creator>>,
+    /// The mint of the collection NFT this token belongs to.
+    pub collection: Option<Pubkey>,
+    pub uses: Option<Uses>,
+    pub is_mutable: bool,
+    /// Set to make this token a sized collection NFT.
+    pub collection_details: Option<CollectionDetails>,
+}
+
+impl SimpleMetadata {
+    /// Checks the fields the same way the Token Metadata program does, so bad
+    /// input fails before any CPI is made.
+    pub fn validate(&self, update_authority: &Pubkey) -> Result<()> {
+        validate_data(
+            &self.name,
+            &self.symbol,
+            &self.uri,
+            self.seller_fee_basis_points,
+            self.creators.as_deref(),
+        )?;
+
+        let verifies_another_creator = self
+            .creators
+            .iter()
+            .flatten()
+            .any(|creator| creator.verified && creator.address != *update_authority);
+        if verifies_another_creator {
+            return Err(SplSimplifiedError::CannotVerifyAnotherCreator.into());
+        }
+
+        if let Some(uses) = &self.uses {
+            let single_use =
+                uses.use_method == UseMethod::Single && (uses.total != 1 || uses.remaining != 1);
+            if uses.remaining > uses.total || single_use {
+                return Err(SplSimplifiedError::InvalidUses.into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Builder for the `mint_simple` flow with named accounts.
+///
+/// Every account is set through its own setter, so two accounts can no longer be
+/// swapped by position. The steps are opt-in: metadata is created when
+/// `metadata` is set, the recipient's associated token account is created when
+/// `create_recipient_ata(true)` is set, and tokens are minted when `amount` is set.
+/// `invoke` checks that every account needed by the enabled steps was provided
+/// before running any of them, and fails with `SplSimplifiedError::MissingAccount`
+/// otherwise; the error names the first missing account as its account name.
+///
+/// Unlike `mint_simple`, the tokens are minted with `mint_authority` as the
+/// authority, and the instruction is built against `token_program`, so Token-2022
+/// mints work as well.
+///
+/// | Step | Accounts |
+/// |------|----------|
+/// | metadata | `payer`, `mint`, `mint_authority`, `metadata_account`, `update_authority`, `token_metadata_program`, `system_program`, `rent` |
+/// | recipient ATA | `payer`, `mint`, `to`, `owner`, `associated_token_program`, `system_program`, `token_program` |
+/// | initial supply | `mint`, `to`, `mint_authority`, `token_program` |
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// use spl_simplified::simplespl::{SimpleMetadata, SimpleMint};
+///
+/// # fn example<'info>(
+/// #     payer_account_info: AccountInfo<'info>,
+/// #     mint_account_info: AccountInfo<'info>,
+/// #     mint_authority_info: AccountInfo<'info>,
+/// #     token_program_info: AccountInfo<'info>,
+/// #     system_program_info: AccountInfo<'info>,
+/// #     rent_sysvar_info: AccountInfo<'info>,
+/// #     token_metadata_program_info: AccountInfo<'info>,
+/// #     metadata_account_info: AccountInfo<'info>,
+/// #     update_authority_info: AccountInfo<'info>,
+/// #     associated_token_program_info: AccountInfo<'info>,
+/// #     recipient_ata_info: AccountInfo<'info>,
+/// #     recipient_info: AccountInfo<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// SimpleMint::new()
+///     .payer(payer_account_info)
+///     .mint(mint_account_info)
+///     .mint_authority(mint_authority_info)
+///     .token_program(token_program_info)
+///     .system_program(system_program_info)
+///     .rent(rent_sysvar_info)
+///     .token_metadata_program(token_metadata_program_info)
+///     .metadata_account(metadata_account_info)
+///     .update_authority(update_authority_info)
+///     .metadata(SimpleMetadata {
+///         name: "TokenName".to_string(),
+///         symbol: "TKN".to_string(),
+///         uri: "https://example.com/token-metadata".to_string(),
+///         seller_fee_basis_points: 500,
+///         ..Default::default()
+///     })
+///     .associated_token_program(associated_token_program_info)
+///     .to(recipient_ata_info)
+///     .owner(recipient_info)
+///     .create_recipient_ata(true)
+///     .amount(1000)
+///     .signer_seeds(&[signer_seeds])
+///     .invoke()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct SimpleMint<'a, 'info> {
+    payer: Option<AccountInfo<'info>>,
+    mint: Option<AccountInfo<'info>>,
+    mint_authority: Option<AccountInfo<'info>>,
+    token_program: Option<AccountInfo<'info>>,
+    system_program: Option<AccountInfo<'info>>,
+    rent: Option<AccountInfo<'info>>,
+    token_metadata_program: Option<AccountInfo<'info>>,
+    metadata_account: Option<AccountInfo<'info>>,
+    update_authority: Option<AccountInfo<'info>>,
+    associated_token_program: Option<AccountInfo<'info>>,
+    to: Option<AccountInfo<'info>>,
+    owner: Option<AccountInfo<'info>>,
+    metadata: Option<SimpleMetadata>,
+    amount: Option<u64>,
+    create_recipient_ata: bool,
+    signer_seeds: &'a [&'a [&'a [u8]]],
+}
+
+impl<'a, 'info> SimpleMint<'a, 'info> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn payer(mut self, payer: AccountInfo<'info>) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+
+    pub fn mint(mut self, mint: AccountInfo<'info>) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+
+    pub fn mint_authority(mut self, mint_authority: AccountInfo<'info>) -> Self {
+        self.mint_authority = Some(mint_authority);
+        self
+    }
+
+    pub fn token_program(mut self, token_program: AccountInfo<'info>) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+
+    pub fn system_program(mut self, system_program: AccountInfo<'info>) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+
+    pub fn rent(mut self, rent: AccountInfo<'info>) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+
+    pub fn token_metadata_program(mut self, token_metadata_program: AccountInfo<'info>) -> Self {
+        self.token_metadata_program = Some(token_metadata_program);
+        self
+    }
+
+    /// The metadata PDA of the mint.
+    pub fn metadata_account(mut self, metadata_account: AccountInfo<'info>) -> Self {
+        self.metadata_account = Some(metadata_account);
+        self
+    }
+
+    pub fn update_authority(mut self, update_authority: AccountInfo<'info>) -> Self {
+        self.update_authority = Some(update_authority);
+        self
+    }
+
+    pub fn associated_token_program(
+        mut self,
+        associated_token_program: AccountInfo<'info>,
+    ) -> Self {
+        self.associated_token_program = Some(associated_token_program);
+        self
+    }
+
+    /// The token account receiving the initial supply.
+    pub fn to(mut self, to: AccountInfo<'info>) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// The owner of `to`, needed to create it as an associated token account.
+    pub fn owner(mut self, owner: AccountInfo<'info>) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+
+    /// Creates the metadata account of the mint.
+    pub fn metadata(mut self, metadata: SimpleMetadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Mints `amount` tokens to `to`.
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Creates `to` as the associated token account of `owner` if it does not exist yet.
+    pub fn create_recipient_ata(mut self, create_recipient_ata: bool) -> Self {
+        self.create_recipient_ata = create_recipient_ata;
+        self
+    }
+
+    pub fn signer_seeds(mut self, signer_seeds: &'a [&'a [&'a [u8]]]) -> Self {
+        self.signer_seeds = signer_seeds;
+        self
+    }
+
+    pub fn invoke(self) -> Result<()> {
+        let mint = required(self.mint, "mint")?;
+        let create_metadata = match self.metadata {
+            Some(metadata) => Some((
+                metadata,
+                required(self.payer.clone(), "payer")?,
+                required(self.token_metadata_program, "token_metadata_program")?,
+                required(self.update_authority, "update_authority")?,
+                required(self.metadata_account, "metadata_account")?,
+                required(self.mint_authority.clone(), "mint_authority")?,
+                required(self.system_program.clone(), "system_program")?,
+                required(self.rent, "rent")?,
+            )),
+            None => None,
+        };
+        let create_ata = if self.create_recipient_ata {
+            Some((
+                required(self.payer, "payer")?,
+                required(self.associated_token_program, "associated_token_program")?,
+                required(self.to.clone(), "to")?,
+                required(self.owner, "owner")?,
+                required(self.system_program, "system_program")?,
+                required(self.token_program.clone(), "token_program")?,
+            ))
+        } else {
+            None
+        };
+        let mint_supply = match self.amount {
+            Some(amount) => Some((
+                amount,
+                required(self.to, "to")?,
+                required(self.mint_authority, "mint_authority")?,
+                required(self.token_program, "token_program")?,
+            )),
+            None => None,
+        };
+
+        if let Some((
+            metadata,
+            payer,
+            token_metadata_program,
+            update_authority,
+            metadata_account,
+            mint_authority,
+            system_program,
+            rent,
+        )) = create_metadata
+        {
+            msg!("SimpleMint: create metadata");
+            metadata_thing(
+                metadata,
+                payer,
+                token_metadata_program,
+                update_authority,
+                mint.clone(),
+                metadata_account,
+                mint_authority,
+                system_program,
+                rent,
+                self.signer_seeds,
+            )?;
+        }
+
+        if let Some((
+            payer,
+            associated_token_program,
+            associated_token,
+            authority,
+            system_program,
+            token_program,
+        )) = create_ata
+        {
+            msg!("SimpleMint: create associated token account");
+            create_idempotent(CpiContext::new(
+                associated_token_program,
+                Create {
+                    payer,
+                    associated_token,
+                    authority,
+                    mint: mint.clone(),
+                    system_program,
+                    token_program,
+                },
+            ))?;
+        }
+
+        if let Some((amount, to, authority, token_program)) = mint_supply {
+            msg!("SimpleMint: mint_to");
+            mint_to(
+                CpiContext::new_with_signer(
+                    token_program,
+                    MintTo {
+                        mint,
+                        to,
+                        authority,
+                    },
+                    self.signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+// Shared with the off-chain `SimpleMint`. The name of the missing account is
+// carried by the error, see `anchor_lang::error::Error::with_account_name`.
+pub(crate) fn required<T>(account: Option<T>, name: &str) -> Result<T> {
+    account.ok_or_else(|| Error::from(SplSimplifiedError::MissingAccount).with_account_name(name))
+}
+
+/// Accounts used by [`mint_with_transfer_fee_simple`].
+#[derive(Accounts)]
+pub struct MintWithTransferFee<'info> {
+    /// Pays for the mint, the recipient's token account and the metadata.
+    pub payer: AccountInfo<'info>,
+    /// The new mint. Must not exist yet and must sign.
+    pub mint: AccountInfo<'info>,
+    /// Set as mint authority, transfer fee config authority and withdraw withheld
+    /// authority of the new mint.
+    pub mint_authority: AccountInfo<'info>,
+    pub update_authority: AccountInfo<'info>,
+    /// The metadata PDA of the mint.
+    pub metadata: AccountInfo<'info>,
+    /// The wallet receiving the initial supply.
+    pub recipient: AccountInfo<'info>,
+    /// The associated token account of `recipient` for the mint.
+    pub recipient_token_account: AccountInfo<'info>,
+    /// The Token-2022 program.
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+/// Arguments of [`mint_with_transfer_fee_simple`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MintWithTransferFeeArgs {
+    pub metadata: SimpleMetadata,
+    /// The transfer fee in basis points.
+    pub transfer_fee_basis_points: u16,
+    /// The largest fee charged on a single transfer, in base units.
+    pub maximum_fee: u64,
+    pub decimals: u8,
+    /// The initial supply minted to the recipient.
+    pub amount: u64,
+}
+
+/// Creates a Token-2022 mint that charges a transfer fee, attaches metadata and
+/// mints the initial supply.
+///
+/// Unlike `mint_simple`, where `token_tax` only ends up as Metaplex
+/// `seller_fee_basis_points`, here `transfer_fee_basis_points` becomes the mint's
+/// transfer fee: Token-2022 withholds it from every transfer of the token. The
+/// mint account is allocated with room for the `TransferFeeConfig` extension, and
+/// the recipient's associated token account is created if it does not exist yet.
+/// The `mint_authority` is also made the transfer fee config authority and the
+/// withdraw withheld authority.
+///
+/// # Arguments
+///
+/// * `accounts` - The accounts of the mint, see [`MintWithTransferFee`].
+/// * `args` - The metadata, transfer fee, decimals and initial supply.
+/// * `signer_seeds` - The seeds of every PDA signing, e.g. the mint or the mint authority.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// use spl_simplified::simplespl::{
+///     mint_with_transfer_fee_simple, MintWithTransferFee, MintWithTransferFeeArgs, SimpleMetadata,
+/// };
+///
+/// # fn example<'info>(
+/// #     payer_account_info: AccountInfo<'info>,
+/// #     mint_account_info: AccountInfo<'info>,
+/// #     mint_authority_info: AccountInfo<'info>,
+/// #     update_authority_info: AccountInfo<'info>,
+/// #     metadata_account_info: AccountInfo<'info>,
+/// #     recipient_info: AccountInfo<'info>,
+/// #     recipient_ata_info: AccountInfo<'info>,
+/// #     token_2022_program_info: AccountInfo<'info>,
+/// #     associated_token_program_info: AccountInfo<'info>,
+/// #     token_metadata_program_info: AccountInfo<'info>,
+/// #     system_program_info: AccountInfo<'info>,
+/// #     rent_sysvar_info: AccountInfo<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// mint_with_transfer_fee_simple(
+///     MintWithTransferFee {
+///         payer: payer_account_info,
+///         mint: mint_account_info,
+///         mint_authority: mint_authority_info,
+///         update_authority: update_authority_info,
+///         metadata: metadata_account_info,
+///         recipient: recipient_info,
+///         recipient_token_account: recipient_ata_info,
+///         token_program: token_2022_program_info,
+///         associated_token_program: associated_token_program_info,
+///         token_metadata_program: token_metadata_program_info,
+///         system_program: system_program_info,
+///         rent: rent_sysvar_info,
+///     },
+///     MintWithTransferFeeArgs {
+///         metadata: SimpleMetadata {
+///             name: "TokenName".to_string(),
+///             symbol: "TKN".to_string(),
+///             uri: "https://example.com/token-metadata".to_string(),
+///             ..Default::default()
+///         },
+///         transfer_fee_basis_points: 250, // 2.5% transfer fee
+///         maximum_fee: u64::MAX,
+///         decimals: 9,
+///         amount: 1000,
+///     },
+///     &[signer_seeds],
+/// ).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub fn mint_with_transfer_fee_simple<'info>(
+    accounts: MintWithTransferFee<'info>,
+    args: MintWithTransferFeeArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let MintWithTransferFee {
+        payer,
+        mint,
+        mint_authority,
+        update_authority,
+        metadata,
+        recipient,
+        recipient_token_account,
+        token_program,
+        associated_token_program,
+        token_metadata_program,
+        system_program,
+        rent,
+    } = accounts;
+
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::TransferFeeConfig,
+    ])?;
+    let lamports = Rent::get()?.minimum_balance(space);
+    msg!("mint_with_transfer_fee_simple: create mint account");
+    create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            CreateAccount {
+                from: payer.clone(),
+                to: mint.clone(),
+            },
+            signer_seeds,
+        ),
+        lamports,
+        space as u64,
+        token_program.key,
+    )?;
+
+    msg!("mint_with_transfer_fee_simple: initialize transfer fee config");
+    initialize_transfer_fee_config(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeTransferFeeConfig { mint: mint.clone() },
+        ),
+        Some(mint_authority.key),
+        Some(mint_authority.key),
+        args.transfer_fee_basis_points,
+        args.maximum_fee,
+    )?;
+
+    msg!("mint_with_transfer_fee_simple: initialize mint");
+    initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeMint2 { mint: mint.clone() },
+        ),
+        args.decimals,
+        mint_authority.key,
+        None,
+    )?;
+
+    msg!("mint_with_transfer_fee_simple: create metadata");
+    metadata_thing(
+        args.metadata,
+        payer.clone(),
+        token_metadata_program,
+        update_authority,
+        mint.clone(),
+        metadata,
+        mint_authority.clone(),
+        system_program.clone(),
+        rent,
+        signer_seeds,
+    )?;
+
+    msg!("mint_with_transfer_fee_simple: create associated token account");
+    create_idempotent(CpiContext::new(
+        associated_token_program,
+        Create {
+            payer,
+            associated_token: recipient_token_account.clone(),
+            authority: recipient,
+            mint: mint.clone(),
+            system_program,
+            token_program: token_program.clone(),
+        },
+    ))?;
+
+    msg!("mint_with_transfer_fee_simple: mint_to");
+    mint_to(
+        CpiContext::new_with_signer(
+            token_program,
+            MintTo {
+                mint,
+                to: recipient_token_account,
+                authority: mint_authority,
+            },
+            signer_seeds,
+        ),
+        args.amount,
+    )
+}
+
+/// Accounts used by [`launch_token_simple`].
+#[derive(Accounts)]
+pub struct LaunchToken<'info> {
+    /// Pays for the mint, the recipient's token account and the metadata.
+    pub payer: AccountInfo<'info>,
+    /// The new mint. Must not exist yet and must sign.
+    pub mint: AccountInfo<'info>,
+    /// Set as mint and freeze authority of the new mint.
+    pub mint_authority: AccountInfo<'info>,
+    pub update_authority: AccountInfo<'info>,
+    /// The metadata PDA of the mint.
+    pub metadata: AccountInfo<'info>,
+    /// The wallet receiving the initial supply.
+    pub recipient: AccountInfo<'info>,
+    /// The associated token account of `recipient` for the mint.
+    pub recipient_token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+/// Arguments of [`launch_token_simple`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LaunchTokenArgs {
+    pub metadata: SimpleMetadata,
+    pub decimals: u8,
+    /// The initial supply minted to the recipient.
+    pub amount: u64,
+    /// Revokes the mint authority once the initial supply is minted, fixing the supply.
+    pub revoke_mint_authority: bool,
+    /// Revokes the freeze authority, so accounts of the mint can never be frozen.
+    pub revoke_freeze_authority: bool,
+}
+
+/// Launches a fungible token in one call.
+///
+/// The mint is allocated through the System Program and initialized with
+/// `initialize_mint2`, with `mint_authority` as mint and freeze authority. Then the
+/// recipient's associated token account is created if it does not exist yet, the
+/// metadata is created, and the initial supply is minted to the recipient.
+/// Finally the mint and freeze authority are revoked if requested. Works with the
+/// legacy Token program and Token-2022.
+///
+/// # Arguments
+///
+/// * `accounts` - The accounts of the launch, see [`LaunchToken`].
+/// * `args` - The metadata, decimals, initial supply and authority lockdown.
+/// * `signer_seeds` - The seeds of every PDA signing, e.g. the mint or the mint authority.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// use spl_simplified::simplespl::{launch_token_simple, LaunchToken, LaunchTokenArgs, SimpleMetadata};
+///
+/// # fn example<'info>(
+/// #     payer_account_info: AccountInfo<'info>,
+/// #     mint_account_info: AccountInfo<'info>,
+/// #     mint_authority_info: AccountInfo<'info>,
+/// #     update_authority_info: AccountInfo<'info>,
+/// #     metadata_account_info: AccountInfo<'info>,
+/// #     recipient_info: AccountInfo<'info>,
+/// #     recipient_ata_info: AccountInfo<'info>,
+/// #     token_program_info: AccountInfo<'info>,
+/// #     associated_token_program_info: AccountInfo<'info>,
+/// #     token_metadata_program_info: AccountInfo<'info>,
+/// #     system_program_info: AccountInfo<'info>,
+/// #     rent_sysvar_info: AccountInfo<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// launch_token_simple(
+///     LaunchToken {
+///         payer: payer_account_info,
+///         mint: mint_account_info,
+///         mint_authority: mint_authority_info,
+///         update_authority: update_authority_info,
+///         metadata: metadata_account_info,
+///         recipient: recipient_info,
+///         recipient_token_account: recipient_ata_info,
+///         token_program: token_program_info,
+///         associated_token_program: associated_token_program_info,
+///         token_metadata_program: token_metadata_program_info,
+///         system_program: system_program_info,
+///         rent: rent_sysvar_info,
+///     },
+///     LaunchTokenArgs {
+///         metadata: SimpleMetadata {
+///             name: "TokenName".to_string(),
+///             symbol: "TKN".to_string(),
+///             uri: "https://example.com/token-metadata".to_string(),
+///             ..Default::default()
+///         },
+///         decimals: 9,
+///         amount: 1_000_000_000_000_000,
+///         revoke_mint_authority: true,
+///         revoke_freeze_authority: true,
+///     },
+///     &[signer_seeds],
+/// ).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub fn launch_token_simple<'info>(
+    accounts: LaunchToken<'info>,
+    args: LaunchTokenArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let LaunchToken {
+        payer,
+        mint,
+        mint_authority,
+        update_authority,
+        metadata,
+        recipient,
+        recipient_token_account,
+        token_program,
+        associated_token_program,
+        token_metadata_program,
+        system_program,
+        rent,
+    } = accounts;
+    args.metadata.validate(update_authority.key)?;
+
+    let space = spl_token::state::Mint::LEN;
+    let lamports = Rent::get()?.minimum_balance(space);
+    msg!("launch_token_simple: create mint account");
+    create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            CreateAccount {
+                from: payer.clone(),
+                to: mint.clone(),
+            },
+            signer_seeds,
+        ),
+        lamports,
+        space as u64,
+        token_program.key,
+    )?;
+
+    msg!("launch_token_simple: initialize mint");
+    initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeMint2 { mint: mint.clone() },
+        ),
+        args.decimals,
+        mint_authority.key,
+        Some(mint_authority.key),
+    )?;
+
+    msg!("launch_token_simple: create associated token account");
+    create_idempotent(CpiContext::new(
+        associated_token_program,
+        Create {
+            payer: payer.clone(),
+            associated_token: recipient_token_account.clone(),
+            authority: recipient,
+            mint: mint.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+        },
+    ))?;
+
+    msg!("launch_token_simple: create metadata");
+    metadata_thing(
+        args.metadata,
+        payer,
+        token_metadata_program,
+        update_authority,
+        mint.clone(),
+        metadata,
+        mint_authority.clone(),
+        system_program,
+        rent,
+        signer_seeds,
+    )?;
+
+    if args.amount > 0 {
+        msg!("launch_token_simple: mint_to");
+        mint_to(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                MintTo {
+                    mint: mint.clone(),
+                    to: recipient_token_account,
+                    authority: mint_authority.clone(),
+                },
+                signer_seeds,
+            ),
+            args.amount,
+        )?;
+    }
+
+    let revocations = [
+        (args.revoke_mint_authority, AuthorityType::MintTokens),
+        (args.revoke_freeze_authority, AuthorityType::FreezeAccount),
+    ];
+    for (revoke, authority_type) in revocations {
+        if !revoke {
+            continue;
+        }
+        msg!("launch_token_simple: revoke authority");
+        set_authority(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                SetAuthority {
+                    current_authority: mint_authority.clone(),
+                    account_or_mint: mint.clone(),
+                },
+                signer_seeds,
+            ),
+            authority_type,
+            None,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Accounts used by [`mint_nft_simple`].
+#[derive(Accounts)]
+pub struct MintNft<'info> {
+    /// Pays for the mint, the owner's token account, the metadata and the edition.
+    pub payer: AccountInfo<'info>,
+    /// The new mint. Must not exist yet and must sign.
+    pub mint: AccountInfo<'info>,
+    /// Mint and freeze authority of the new mint, both handed to the master edition.
+    pub mint_authority: AccountInfo<'info>,
+    pub update_authority: AccountInfo<'info>,
+    /// The metadata PDA of the mint.
+    pub metadata: AccountInfo<'info>,
+    /// The master edition PDA of the mint.
+    pub master_edition: AccountInfo<'info>,
+    /// The wallet receiving the NFT.
+    pub owner: AccountInfo<'info>,
+    /// The associated token account of `owner` for the mint.
+    pub owner_token_account: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+/// The collection a [`mint_nft_simple`] NFT is added to and verified in.
+pub struct NftCollection<'info> {
+    /// The update authority of the collection, or a delegate with a collection authority record.
+    pub collection_authority: AccountInfo<'info>,
+    pub collection_mint: AccountInfo<'info>,
+    pub collection_metadata: AccountInfo<'info>,
+    pub collection_master_edition: AccountInfo<'info>,
+    /// The collection authority record PDA, when `collection_authority` is a delegate.
+    pub collection_authority_record: Option<AccountInfo<'info>>,
+}
+
+/// Arguments of [`mint_nft_simple`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MintNftArgs {
+    pub metadata: SimpleMetadata,
+    /// The number of prints the master edition allows; `Some(0)` makes a 1/1 and
+    /// `None` allows unlimited prints.
+    pub max_supply: Option<u64>,
+}
+
+/// Mints an NFT in one call.
+///
+/// The mint is allocated through the System Program and initialized with 0
+/// decimals, the owner's associated token account is created if it does not exist
+/// yet, and exactly one token is minted into it. Then the metadata and the master
+/// edition are created; the master edition takes over the mint and freeze
+/// authority. When `collection` is given, the NFT's metadata points to it and the
+/// collection is verified, using the sized or unsized instruction depending on the
+/// collection metadata. PDA authorities sign through `signer_seeds`.
+///
+/// # Arguments
+///
+/// * `accounts` - The accounts of the NFT, see [`MintNft`].
+/// * `collection` - The collection to add the NFT to, if any.
+/// * `args` - The metadata and the master edition max supply.
+/// * `signer_seeds` - The seeds of every PDA signing, e.g. the authorities.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// use spl_simplified::simplespl::{mint_nft_simple, MintNft, MintNftArgs, NftCollection, SimpleMetadata};
+///
+/// # fn example<'info>(
+/// #     payer_account_info: AccountInfo<'info>,
+/// #     mint_account_info: AccountInfo<'info>,
+/// #     authority_pda_info: AccountInfo<'info>,
+/// #     metadata_account_info: AccountInfo<'info>,
+/// #     master_edition_info: AccountInfo<'info>,
+/// #     owner_info: AccountInfo<'info>,
+/// #     owner_ata_info: AccountInfo<'info>,
+/// #     token_program_info: AccountInfo<'info>,
+/// #     associated_token_program_info: AccountInfo<'info>,
+/// #     token_metadata_program_info: AccountInfo<'info>,
+/// #     system_program_info: AccountInfo<'info>,
+/// #     rent_sysvar_info: AccountInfo<'info>,
+/// #     collection_mint_info: AccountInfo<'info>,
+/// #     collection_metadata_info: AccountInfo<'info>,
+/// #     collection_master_edition_info: AccountInfo<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// mint_nft_simple(
+///     MintNft {
+///         payer: payer_account_info,
+///         mint: mint_account_info,
+///         mint_authority: authority_pda_info.clone(),
+///         update_authority: authority_pda_info.clone(),
+///         metadata: metadata_account_info,
+///         master_edition: master_edition_info,
+///         owner: owner_info,
+///         owner_token_account: owner_ata_info,
+///         token_program: token_program_info,
+///         associated_token_program: associated_token_program_info,
+///         token_metadata_program: token_metadata_program_info,
+///         system_program: system_program_info,
+///         rent: rent_sysvar_info,
+///     },
+///     Some(NftCollection {
+///         collection_authority: authority_pda_info,
+///         collection_mint: collection_mint_info,
+///         collection_metadata: collection_metadata_info,
+///         collection_master_edition: collection_master_edition_info,
+///         collection_authority_record: None,
+///     }),
+///     MintNftArgs {
+///         metadata: SimpleMetadata {
+///             name: "Artwork #1".to_string(),
+///             symbol: "ART".to_string(),
+///             uri: "https://example.com/artwork-1.json".to_string(),
+///             seller_fee_basis_points: 500,
+///             ..Default::default()
+///         },
+///         max_supply: Some(0), // 1/1
+///     },
+///     &[signer_seeds],
+/// ).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub fn mint_nft_simple<'info>(
+    accounts: MintNft<'info>,
+    collection: Option<NftCollection<'info>>,
+    args: MintNftArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let MintNft {
+        payer,
+        mint,
+        mint_authority,
+        update_authority,
+        metadata,
+        master_edition,
+        owner,
+        owner_token_account,
+        token_program,
+        associated_token_program,
+        token_metadata_program,
+        system_program,
+        rent,
+    } = accounts;
+    let mut metadata_args = args.metadata;
+    if let Some(collection) = &collection {
+        metadata_args.collection = Some(collection.collection_mint.key());
+    }
+    metadata_args.validate(update_authority.key)?;
+
+    let space = spl_token::state::Mint::LEN;
+    let lamports = Rent::get()?.minimum_balance(space);
+    msg!("mint_nft_simple: create mint account");
+    create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            CreateAccount {
+                from: payer.clone(),
+                to: mint.clone(),
+            },
+            signer_seeds,
+        ),
+        lamports,
+        space as u64,
+        token_program.key,
+    )?;
+
+    msg!("mint_nft_simple: initialize mint");
+    initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeMint2 { mint: mint.clone() },
+        ),
+        0,
+        mint_authority.key,
+        Some(mint_authority.key),
+    )?;
+
+    msg!("mint_nft_simple: create associated token account");
+    create_idempotent(CpiContext::new(
+        associated_token_program,
+        Create {
+            payer: payer.clone(),
+            associated_token: owner_token_account.clone(),
+            authority: owner,
+            mint: mint.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+        },
+    ))?;
+
+    msg!("mint_nft_simple: mint_to");
+    mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            MintTo {
+                mint: mint.clone(),
+                to: owner_token_account,
+                authority: mint_authority.clone(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    msg!("mint_nft_simple: create metadata");
+    metadata_thing(
+        metadata_args,
+        payer.clone(),
+        token_metadata_program.clone(),
+        update_authority.clone(),
+        mint.clone(),
+        metadata.clone(),
+        mint_authority.clone(),
+        system_program.clone(),
+        rent.clone(),
+        signer_seeds,
+    )?;
+
+    msg!("mint_nft_simple: create master edition");
+    create_master_edition_v3(
+        CpiContext::new_with_signer(
+            token_metadata_program.clone(),
+            CreateMasterEditionV3 {
+                edition: master_edition,
+                mint,
+                update_authority,
+                mint_authority,
+                payer: payer.clone(),
+                metadata: metadata.clone(),
+                token_program,
+                system_program,
+                rent,
+            },
+            signer_seeds,
+        ),
+        args.max_supply,
+    )?;
+
+    let Some(collection) = collection else {
+        return Ok(());
+    };
+    let sized = read_metadata(&collection.collection_metadata)?
+        .collection_details
+        .is_some();
+    let NftCollection {
+        collection_authority,
+        collection_mint,
+        collection_metadata,
+        collection_master_edition,
+        collection_authority_record,
+    } = collection;
+    let record_key = collection_authority_record
+        .as_ref()
+        .map(|record| record.key());
+    let remaining_accounts = collection_authority_record.into_iter().collect();
+
+    msg!("mint_nft_simple: verify collection");
+    if sized {
+        verify_sized_collection_item(
+            CpiContext::new_with_signer(
+                token_metadata_program,
+                VerifySizedCollectionItem {
+                    payer,
+                    metadata,
+                    collection_authority,
+                    collection_mint,
+                    collection_metadata,
+                    collection_master_edition,
+                },
+                signer_seeds,
+            )
+            .with_remaining_accounts(remaining_accounts),
+            record_key,
+        )
+    } else {
+        verify_collection(
+            CpiContext::new_with_signer(
+                token_metadata_program,
+                VerifyCollection {
+                    payer,
+                    metadata,
+                    collection_authority,
+                    collection_mint,
+                    collection_metadata,
+                    collection_master_edition,
+                },
+                signer_seeds,
+            )
+            .with_remaining_accounts(remaining_accounts),
+            record_key,
+        )
+    }
+}
+
+/// Creates a sized collection NFT.
+///
+/// Mints the collection NFT like [`mint_nft_simple`] and marks its metadata as a
+/// sized collection, starting with a size of 0 that the Token Metadata program
+/// keeps up to date as items are verified and unverified. `metadata` and
+/// `master_edition` are checked to be the PDAs of `mint`.
+///
+/// # Arguments
+///
+/// * `accounts` - The accounts of the collection NFT, see [`MintNft`].
+/// * `args` - The metadata and the master edition max supply, usually `Some(0)`.
+/// * `signer_seeds` - The seeds of every PDA signing, e.g. the authorities.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// # use spl_simplified::simplespl::MintNft;
+/// use spl_simplified::simplespl::{create_collection_simple, MintNftArgs, SimpleMetadata};
+///
+/// # fn example<'info>(
+/// #     mint_nft_accounts: MintNft<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// create_collection_simple(
+///     mint_nft_accounts,
+///     MintNftArgs {
+///         metadata: SimpleMetadata {
+///             name: "My Collection".to_string(),
+///             symbol: "COL".to_string(),
+///             uri: "https://example.com/collection.json".to_string(),
+///             ..Default::default()
+///         },
+///         max_supply: Some(0),
+///     },
+///     &[signer_seeds],
+/// ).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_collection_simple<'info>(
+    accounts: MintNft<'info>,
+    mut args: MintNftArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    assert_metadata(&accounts.metadata, accounts.mint.key)?;
+    assert_master_edition(&accounts.master_edition, accounts.mint.key)?;
+
+    args.metadata.collection_details = Some(CollectionDetails::V1 { size: 0 });
+    mint_nft_simple(accounts, None, args, signer_seeds)
+}
+
+/// Accounts used by [`add_to_collection_simple`] and [`remove_from_collection_simple`].
+pub struct CollectionItem<'info> {
+    pub payer: AccountInfo<'info>,
+    /// The metadata of the item NFT.
+    pub item_metadata: AccountInfo<'info>,
+    /// The update authority of the item, needed when the item does not point to the
+    /// collection yet.
+    pub item_update_authority: AccountInfo<'info>,
+    /// The update authority of the collection, or a delegate with a collection authority record.
+    pub collection_authority: AccountInfo<'info>,
+    pub collection_mint: AccountInfo<'info>,
+    pub collection_metadata: AccountInfo<'info>,
+    pub collection_master_edition: AccountInfo<'info>,
+    /// The collection authority record PDA, when `collection_authority` is a delegate.
+    pub collection_authority_record: Option<AccountInfo<'info>>,
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+impl<'info> CollectionItem<'info> {
+    fn assert_pdas(&self) -> Result<Metadata> {
+        let item = read_metadata(&self.item_metadata)?;
+        assert_metadata(&self.item_metadata, &item.mint)?;
+        assert_collection_pdas(
+            &self.collection_mint,
+            &self.collection_metadata,
+            Some(&self.collection_master_edition),
+            &self.collection_authority,
+            self.collection_authority_record.as_ref(),
+        )?;
+        Ok(item)
+    }
+}
+
+/// Adds an item to a collection and verifies it.
+///
+/// If the item's metadata already points to the collection, the item is only
+/// verified; otherwise the collection is set and verified in one instruction,
+/// which needs the item's update authority to sign. Sized and unsized
+/// collections are both handled. Items that are already verified members are
+/// left untouched. The metadata, master edition and collection authority record
+/// accounts are checked against their PDAs first.
+///
+/// # Arguments
+///
+/// * `accounts` - The item and collection accounts, see [`CollectionItem`].
+/// * `signer_seeds` - The seeds of every PDA signing, e.g. the collection authority.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// # use spl_simplified::simplespl::CollectionItem;
+/// use spl_simplified::simplespl::add_to_collection_simple;
+///
+/// # fn example<'info>(
+/// #     collection_item_accounts: CollectionItem<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// add_to_collection_simple(collection_item_accounts, &[signer_seeds]).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub fn add_to_collection_simple<'info>(
+    accounts: CollectionItem<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let item = accounts.assert_pdas()?;
+    let sized = read_metadata(&accounts.collection_metadata)?
+        .collection_details
+        .is_some();
+    let points_to_collection = match &item.collection {
+        Some(collection) if collection.key == *accounts.collection_mint.key => {
+            if collection.verified {
+                return Ok(());
+            }
+            true
+        }
+        _ => false,
+    };
+
+    let CollectionItem {
+        payer,
+        item_metadata: metadata,
+        item_update_authority: update_authority,
+        collection_authority,
+        collection_mint,
+        collection_metadata,
+        collection_master_edition,
+        collection_authority_record,
+        token_metadata_program: program,
+    } = accounts;
+    let record_key = collection_authority_record
+        .as_ref()
+        .map(|record| record.key());
+    let remaining_accounts: Vec<_> = collection_authority_record.into_iter().collect();
+
+    msg!("add_to_collection_simple: verify item");
+    match (points_to_collection, sized) {
+        (true, true) => verify_sized_collection_item(
+            CpiContext::new_with_signer(
+                program,
+                VerifySizedCollectionItem {
+                    payer,
+                    metadata,
+                    collection_authority,
+                    collection_mint,
+                    collection_metadata,
+                    collection_master_edition,
+                },
+                signer_seeds,
+            )
+            .with_remaining_accounts(remaining_accounts),
+            record_key,
+        ),
+        (true, false) => verify_collection(
+            CpiContext::new_with_signer(
+                program,
+                VerifyCollection {
+                    payer,
+                    metadata,
+                    collection_authority,
+                    collection_mint,
+                    collection_metadata,
+                    collection_master_edition,
+                },
+                signer_seeds,
+            )
+            .with_remaining_accounts(remaining_accounts),
+            record_key,
+        ),
+        (false, true) => set_and_verify_sized_collection_item(
+            CpiContext::new_with_signer(
+                program,
+                SetAndVerifySizedCollectionItem {
+                    metadata,
+                    collection_authority,
+                    payer,
+                    update_authority,
+                    collection_mint,
+                    collection_metadata,
+                    collection_master_edition,
+                },
+                signer_seeds,
+            )
+            .with_remaining_accounts(remaining_accounts),
+            record_key,
+        ),
+        (false, false) => set_and_verify_collection(
+            CpiContext::new_with_signer(
+                program,
+                SetAndVerifyCollection {
+                    metadata,
+                    collection_authority,
+                    payer,
+                    update_authority,
+                    collection_mint,
+                    collection_metadata,
+                    collection_master_edition,
+                },
+                signer_seeds,
+            )
+            .with_remaining_accounts(remaining_accounts),
+            record_key,
+        ),
+    }
+}
+
+/// Removes a verified item from a collection.
+///
+/// The item is unverified, which also decrements the size of a sized collection;
+/// its metadata keeps pointing to the collection unverified. Fails with
+/// `SplSimplifiedError::NotInCollection` if the item is not a verified member of
+/// the collection. `item_update_authority` is not used.
+///
+/// # Arguments
+///
+/// * `accounts` - The item and collection accounts, see [`CollectionItem`].
+/// * `signer_seeds` - The seeds of every PDA signing, e.g. the collection authority.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// # use spl_simplified::simplespl::CollectionItem;
+/// use spl_simplified::simplespl::remove_from_collection_simple;
+///
+/// # fn example<'info>(
+/// #     collection_item_accounts: CollectionItem<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// remove_from_collection_simple(collection_item_accounts, &[signer_seeds]).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub fn remove_from_collection_simple<'info>(
+    accounts: CollectionItem<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let item = accounts.assert_pdas()?;
+    match &item.collection {
+        Some(collection)
+            if collection.key == *accounts.collection_mint.key && collection.verified => {}
+        _ => return Err(SplSimplifiedError::NotInCollection.into()),
+    }
+    let sized = read_metadata(&accounts.collection_metadata)?
+        .collection_details
+        .is_some();
+
+    let CollectionItem {
+        payer,
+        item_metadata: metadata,
+        collection_authority,
+        collection_mint,
+        collection_metadata: collection,
+        collection_master_edition: collection_master_edition_account,
+        collection_authority_record,
+        token_metadata_program: program,
+        ..
+    } = accounts;
+    let record_key = collection_authority_record
+        .as_ref()
+        .map(|record| record.key());
+    let remaining_accounts: Vec<_> = collection_authority_record.into_iter().collect();
+
+    msg!("remove_from_collection_simple: unverify item");
+    if sized {
+        unverify_sized_collection_item(
+            CpiContext::new_with_signer(
+                program,
+                UnverifySizedCollectionItem {
+                    metadata,
+                    collection_authority,
+                    payer,
+                    collection_mint,
+                    collection,
+                    collection_master_edition_account,
+                },
+                signer_seeds,
+            )
+            .with_remaining_accounts(remaining_accounts),
+            record_key,
+        )
+    } else {
+        unverify_collection(
+            CpiContext::new_with_signer(
+                program,
+                UnverifyCollection {
+                    metadata,
+                    collection_authority,
+                    collection_mint,
+                    collection,
+                    collection_master_edition_account,
+                },
+                signer_seeds,
+            )
+            .with_remaining_accounts(remaining_accounts),
+            record_key,
+        )
+    }
+}
+
+/// Accounts used by [`migrate_collection_to_sized_simple`].
+pub struct MigrateCollection<'info> {
+    /// The update authority of the collection, or a delegate with a collection authority record.
+    pub collection_authority: AccountInfo<'info>,
+    pub collection_mint: AccountInfo<'info>,
+    pub collection_metadata: AccountInfo<'info>,
+    /// The collection authority record PDA, when `collection_authority` is a delegate.
+    pub collection_authority_record: Option<AccountInfo<'info>>,
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Migrates an unsized collection to a sized one.
+///
+/// `size` has to be the number of items currently verified in the collection;
+/// the Token Metadata program keeps it up to date from then on. The metadata and
+/// collection authority record accounts are checked against their PDAs first.
+///
+/// # Arguments
+///
+/// * `accounts` - The collection accounts, see [`MigrateCollection`].
+/// * `size` - The number of verified items in the collection.
+/// * `signer_seeds` - The seeds of every PDA signing, e.g. the collection authority.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// use spl_simplified::simplespl::{migrate_collection_to_sized_simple, MigrateCollection};
+///
+/// # fn example<'info>(
+/// #     authority_info: AccountInfo<'info>,
+/// #     collection_mint_info: AccountInfo<'info>,
+/// #     collection_metadata_info: AccountInfo<'info>,
+/// #     token_metadata_program_info: AccountInfo<'info>,
+/// #     system_program_info: AccountInfo<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// migrate_collection_to_sized_simple(
+///     MigrateCollection {
+///         collection_authority: authority_info,
+///         collection_mint: collection_mint_info,
+///         collection_metadata: collection_metadata_info,
+///         collection_authority_record: None,
+///         token_metadata_program: token_metadata_program_info,
+///         system_program: system_program_info,
+///     },
+///     10_000,
+///     &[signer_seeds],
+/// ).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub fn migrate_collection_to_sized_simple<'info>(
+    accounts: MigrateCollection<'info>,
+    size: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    assert_collection_pdas(
+        &accounts.collection_mint,
+        &accounts.collection_metadata,
+        None,
+        &accounts.collection_authority,
+        accounts.collection_authority_record.as_ref(),
+    )?;
+
+    let record_key = accounts
+        .collection_authority_record
+        .as_ref()
+        .map(|record| record.key());
+    let remaining_accounts: Vec<_> = accounts.collection_authority_record.into_iter().collect();
+
+    msg!("migrate_collection_to_sized_simple: set collection size");
+    set_collection_size(
+        CpiContext::new_with_signer(
+            accounts.token_metadata_program,
+            SetCollectionSize {
+                metadata: accounts.collection_metadata,
+                mint: accounts.collection_mint,
+                update_authority: accounts.collection_authority,
+                system_program: accounts.system_program,
+            },
+            signer_seeds,
+        )
+        .with_remaining_accounts(remaining_accounts),
+        record_key,
+        size,
+    )
+}
+
+/// Accounts used by [`print_edition_simple`].
+#[derive(Accounts)]
+pub struct PrintEdition<'info> {
+    /// Pays for the new mint, the recipient's token account, the metadata and the edition.
+    pub payer: AccountInfo<'info>,
+    /// The mint of the print. Must not exist yet and must sign.
+    pub new_mint: AccountInfo<'info>,
+    /// Mint and freeze authority of the new mint, both handed to the new edition.
+    pub new_mint_authority: AccountInfo<'info>,
+    /// The metadata PDA of `new_mint`.
+    pub new_metadata: AccountInfo<'info>,
+    /// The edition PDA of `new_mint`.
+    pub new_edition: AccountInfo<'info>,
+    pub new_metadata_update_authority: AccountInfo<'info>,
+    /// The wallet receiving the print.
+    pub recipient: AccountInfo<'info>,
+    /// The associated token account of `recipient` for `new_mint`.
+    pub recipient_token_account: AccountInfo<'info>,
+    /// The mint of the master edition NFT.
+    pub master_mint: AccountInfo<'info>,
+    /// The metadata PDA of `master_mint`.
+    pub master_metadata: AccountInfo<'info>,
+    /// The master edition PDA of `master_mint`.
+    pub master_edition: AccountInfo<'info>,
+    /// The token account holding the master edition NFT.
+    pub master_token_account: AccountInfo<'info>,
+    /// The owner of `master_token_account`.
+    pub master_token_account_owner: AccountInfo<'info>,
+    /// The edition marker PDA covering the printed edition number.
+    pub edition_marker: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+/// Prints the next edition of a master edition NFT to a recipient.
+///
+/// The edition number is the master edition's current supply plus one; printing
+/// fails with `SplSimplifiedError::MaxEditionsMintedAlready` once the max supply is
+/// reached. The new metadata, the new edition and the edition marker (one marker
+/// per 248 editions, seeded with `edition / 248`) are checked against their PDAs
+/// before anything is invoked. Then the new mint is created with 0 decimals, one
+/// token is minted to the recipient's associated token account, created if needed,
+/// and the print is made through the holder of the master edition token. Returns
+/// the printed edition number.
+///
+/// Prints are made with the legacy Token program; any other `token_program` fails
+/// with `ErrorCode::InvalidProgramId`.
+///
+/// # Arguments
+///
+/// * `accounts` - The accounts of the print, see [`PrintEdition`].
+/// * `signer_seeds` - The seeds of every PDA signing, e.g. the new mint authority.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// # use spl_simplified::simplespl::PrintEdition;
+/// use spl_simplified::simplespl::print_edition_simple;
+///
+/// # fn example<'info>(
+/// #     print_edition_accounts: PrintEdition<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// let edition = print_edition_simple(print_edition_accounts, &[signer_seeds]).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub fn print_edition_simple<'info>(
+    accounts: PrintEdition<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<u64> {
+    require_keys_eq!(
+        accounts.token_program.key(),
+        spl_token::ID,
+        ErrorCode::InvalidProgramId
+    );
+    let master_mint = accounts.master_mint.key;
+    assert_metadata(&accounts.master_metadata, master_mint)?;
+    assert_master_edition(&accounts.master_edition, master_mint)?;
+
+    let master_edition = {
+        if *accounts.master_edition.owner != mpl_token_metadata::ID {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        let data = accounts.master_edition.try_borrow_data()?;
+        MasterEditionAccount::try_deserialize(&mut &data[..])?
+    };
+    let edition = master_edition
+        .supply
+        .checked_add(1)
+        .ok_or(SplSimplifiedError::MaxEditionsMintedAlready)?;
+    if master_edition
+        .max_supply
+        .is_some_and(|max_supply| edition > max_supply)
+    {
+        return Err(SplSimplifiedError::MaxEditionsMintedAlready.into());
+    }
+
+    assert_metadata(&accounts.new_metadata, accounts.new_mint.key)?;
+    assert_edition(&accounts.new_edition, accounts.new_mint.key)?;
+    assert_edition_marker(&accounts.edition_marker, master_mint, edition)?;
+
+    let space = spl_token::state::Mint::LEN;
+    let lamports = Rent::get()?.minimum_balance(space);
+    msg!("print_edition_simple: create mint account");
+    create_account(
+        CpiContext::new_with_signer(
+            accounts.system_program.clone(),
+            CreateAccount {
+                from: accounts.payer.clone(),
+                to: accounts.new_mint.clone(),
+            },
+            signer_seeds,
+        ),
+        lamports,
+        space as u64,
+        accounts.token_program.key,
+    )?;
+
+    msg!("print_edition_simple: initialize mint");
+    initialize_mint2(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            InitializeMint2 {
+                mint: accounts.new_mint.clone(),
+            },
+        ),
+        0,
+        accounts.new_mint_authority.key,
+        Some(accounts.new_mint_authority.key),
+    )?;
+
+    msg!("print_edition_simple: create associated token account");
+    create_idempotent(CpiContext::new(
+        accounts.associated_token_program.clone(),
+        Create {
+            payer: accounts.payer.clone(),
+            associated_token: accounts.recipient_token_account.clone(),
+            authority: accounts.recipient.clone(),
+            mint: accounts.new_mint.clone(),
+            system_program: accounts.system_program.clone(),
+            token_program: accounts.token_program.clone(),
+        },
+    ))?;
+
+    msg!("print_edition_simple: mint_to");
+    mint_to(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            MintTo {
+                mint: accounts.new_mint.clone(),
+                to: accounts.recipient_token_account,
+                authority: accounts.new_mint_authority.clone(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    msg!("print_edition_simple: print edition");
+    mint_new_edition_from_master_edition_via_token(
+        CpiContext::new_with_signer(
+            accounts.token_metadata_program,
+            MintNewEditionFromMasterEditionViaToken {
+                new_metadata: accounts.new_metadata,
+                new_edition: accounts.new_edition,
+                master_edition: accounts.master_edition,
+                new_mint: accounts.new_mint,
+                edition_mark_pda: accounts.edition_marker,
+                new_mint_authority: accounts.new_mint_authority,
+                payer: accounts.payer,
+                token_account_owner: accounts.master_token_account_owner,
+                token_account: accounts.master_token_account,
+                new_metadata_update_authority: accounts.new_metadata_update_authority,
+                metadata: accounts.master_metadata,
+                token_program: accounts.token_program,
+                system_program: accounts.system_program,
+                rent: accounts.rent,
+                metadata_mint: accounts.master_mint,
+            },
+            signer_seeds,
+        ),
+        edition,
+    )?;
+
+    Ok(edition)
+}
+
+/// A token delegate of a programmable NFT, used by [`delegate_pnft_simple`] and
+/// [`revoke_pnft_simple`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PnftDelegateRole {
+    Sale,
+    Transfer,
+    Utility,
+    Staking,
+    /// A transfer delegate that can only transfer to `locked_address`.
+    LockedTransfer {
+        locked_address: Pubkey,
+    },
+}
+
+impl PnftDelegateRole {
+    pub(crate) fn delegate_args(
+        self,
+        authorization_data: Option<AuthorizationData>,
+    ) -> DelegateArgs {
+        let amount = 1;
+        match self {
+            Self::Sale => DelegateArgs::SaleV1 {
+                amount,
+                authorization_data,
+            },
+            Self::Transfer => DelegateArgs::TransferV1 {
+                amount,
+                authorization_data,
+            },
+            Self::Utility => DelegateArgs::UtilityV1 {
+                amount,
+                authorization_data,
+            },
+            Self::Staking => DelegateArgs::StakingV1 {
+                amount,
+                authorization_data,
+            },
+            Self::LockedTransfer { locked_address } => DelegateArgs::LockedTransferV1 {
+                amount,
+                locked_address,
+                authorization_data,
+            },
+        }
+    }
+
+    pub(crate) fn revoke_args(self) -> RevokeArgs {
+        match self {
+            Self::Sale => RevokeArgs::SaleV1,
+            Self::Transfer => RevokeArgs::TransferV1,
+            Self::Utility => RevokeArgs::UtilityV1,
+            Self::Staking => RevokeArgs::StakingV1,
+            Self::LockedTransfer { .. } => RevokeArgs::LockedTransferV1,
+        }
+    }
+}
+
+/// Accounts used by [`transfer_pnft_simple`].
+pub struct TransferPnft<'info> {
+    pub payer: AccountInfo<'info>,
+    /// The owner of `token`, or its transfer, sale or locked transfer delegate.
+    pub authority: AccountInfo<'info>,
+    pub token_owner: AccountInfo<'info>,
+    /// The token account holding the pNFT.
+    pub token: AccountInfo<'info>,
+    pub destination_owner: AccountInfo<'info>,
+    /// The associated token account of `destination_owner`, created if missing.
+    pub destination_token: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    /// The metadata PDA of `mint`.
+    pub metadata: AccountInfo<'info>,
+    /// The master edition or edition PDA of `mint`.
+    pub edition: AccountInfo<'info>,
+    /// The token record PDA of `token`.
+    pub token_record: AccountInfo<'info>,
+    /// The token record PDA of `destination_token`, created if missing.
+    pub destination_token_record: AccountInfo<'info>,
+    /// The rule set named by the metadata, if it has one.
+    pub authorization_rules: Option<AccountInfo<'info>>,
+    /// The token-auth-rules program, needed with `authorization_rules`.
+    pub authorization_rules_program: Option<AccountInfo<'info>>,
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Transfers a programmable NFT.
+///
+/// The metadata and edition are checked against their PDAs, and the owner and
+/// destination token records against the PDAs derived from the mint and the two
+/// token accounts. When the metadata names a rule set, `authorization_rules` must
+/// be that rule set and `authorization_data` is handed to the rule evaluation.
+///
+/// # Arguments
+///
+/// * `accounts` - The accounts of the transfer, see [`TransferPnft`].
+/// * `authorization_data` - The payload evaluated against the rule set, if any.
+/// * `signer_seeds` - The seeds of every PDA signing, e.g. the authority.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// # use spl_simplified::simplespl::TransferPnft;
+/// use spl_simplified::simplespl::transfer_pnft_simple;
+///
+/// # fn example<'info>(
+/// #     transfer_pnft_accounts: TransferPnft<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// transfer_pnft_simple(transfer_pnft_accounts, None, &[signer_seeds]).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub fn transfer_pnft_simple<'info>(
+    accounts: TransferPnft<'info>,
+    authorization_data: Option<AuthorizationData>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let (rule_set, rule_accounts) = assert_pnft_accounts(
+        &accounts.mint,
+        &accounts.metadata,
+        &accounts.edition,
+        &accounts.sysvar_instructions,
+        accounts.authorization_rules.as_ref(),
+        accounts.authorization_rules_program.as_ref(),
+    )?;
+    assert_token_record(
+        &accounts.token_record,
+        accounts.mint.key,
+        accounts.token.key,
+    )?;
+    assert_token_record(
+        &accounts.destination_token_record,
+        accounts.mint.key,
+        accounts.destination_token.key,
+    )?;
+
+    let mut remaining_accounts = vec![
+        accounts.edition.clone(),
+        accounts.token_record.clone(),
+        accounts.destination_token_record.clone(),
+    ];
+    remaining_accounts.extend(rule_accounts);
+
+    msg!("transfer_pnft_simple: transfer");
+    transfer_v1(
+        CpiContext::new_with_signer(
+            accounts.token_metadata_program,
+            TransferV1 {
+                token: accounts.token,
+                token_owner: accounts.token_owner,
+                destination_token: accounts.destination_token,
+                destination_owner: accounts.destination_owner,
+                mint: accounts.mint,
+                metadata: accounts.metadata,
+                authority: accounts.authority,
+                payer: accounts.payer,
+                system_program: accounts.system_program,
+                sysvar_instructions: accounts.sysvar_instructions,
+                spl_token_program: accounts.token_program,
+                spl_ata_program: accounts.associated_token_program,
+            },
+            signer_seeds,
+        )
+        .with_remaining_accounts(remaining_accounts),
+        Some(accounts.edition.key()),
+        Some(accounts.token_record.key()),
+        Some(accounts.destination_token_record.key()),
+        rule_set,
+        TransferArgs::V1 {
+            amount: 1,
+            authorization_data,
+        },
+    )
+}
+
+/// Accounts used by [`delegate_pnft_simple`] and [`revoke_pnft_simple`].
+pub struct DelegatePnft<'info> {
+    pub payer: AccountInfo<'info>,
+    /// The owner of `token`.
+    pub authority: AccountInfo<'info>,
+    pub delegate: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    /// The metadata PDA of `mint`.
+    pub metadata: AccountInfo<'info>,
+    /// The master edition or edition PDA of `mint`.
+    pub edition: AccountInfo<'info>,
+    /// The token account holding the pNFT.
+    pub token: AccountInfo<'info>,
+    /// The token record PDA of `token`.
+    pub token_record: AccountInfo<'info>,
+    /// The rule set named by the metadata, if it has one.
+    pub authorization_rules: Option<AccountInfo<'info>>,
+    /// The token-auth-rules program, needed with `authorization_rules`.
+    pub authorization_rules_program: Option<AccountInfo<'info>>,
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> DelegatePnft<'info> {
+    fn assert_pdas(&self) -> Result<(Option<Pubkey>, Vec<AccountInfo<'info>>)> {
+        let rules = assert_pnft_accounts(
+            &self.mint,
+            &self.metadata,
+            &self.edition,
+            &self.sysvar_instructions,
+            self.authorization_rules.as_ref(),
+            self.authorization_rules_program.as_ref(),
+        )?;
+        assert_token_record(&self.token_record, self.mint.key, self.token.key)?;
+        Ok(rules)
+    }
+}
+
+/// Approves a token delegate on a programmable NFT.
+///
+/// A pNFT holds a single token delegate, recorded in its token record; the
+/// token record is checked against the PDA of the mint and `token`, and the
+/// rule set accounts are checked like in [`transfer_pnft_simple`].
+///
+/// # Arguments
+///
+/// * `accounts` - The accounts of the delegation, see [`DelegatePnft`].
+/// * `role` - The delegate to approve.
+/// * `authorization_data` - The payload evaluated against the rule set, if any.
+/// * `signer_seeds` - The seeds of every PDA signing, e.g. the owner.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// # use spl_simplified::simplespl::DelegatePnft;
+/// use spl_simplified::simplespl::{delegate_pnft_simple, PnftDelegateRole};
+///
+/// # fn example<'info>(
+/// #     delegate_accounts: DelegatePnft<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// delegate_pnft_simple(delegate_accounts, PnftDelegateRole::Staking, None, &[signer_seeds])
+///     .unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub fn delegate_pnft_simple<'info>(
+    accounts: DelegatePnft<'info>,
+    role: PnftDelegateRole,
+    authorization_data: Option<AuthorizationData>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let (rule_set, rule_accounts) = accounts.assert_pdas()?;
+
+    let mut remaining_accounts = vec![
+        accounts.edition.clone(),
+        accounts.token_record.clone(),
+        accounts.token.clone(),
+    ];
+    remaining_accounts.extend(rule_accounts);
+
+    msg!("delegate_pnft_simple: delegate");
+    delegate_v1(
+        CpiContext::new_with_signer(
+            accounts.token_metadata_program,
+            DelegateV1 {
+                delegate: accounts.delegate,
+                metadata: accounts.metadata,
+                mint: accounts.mint,
+                authority: accounts.authority,
+                payer: accounts.payer,
+                system_program: accounts.system_program,
+                sysvar_instructions: accounts.sysvar_instructions,
+                spl_token_program: accounts.token_program,
+            },
+            signer_seeds,
+        )
+        .with_remaining_accounts(remaining_accounts),
+        None,
+        Some(accounts.edition.key()),
+        Some(accounts.token_record.key()),
+        Some(accounts.token.key()),
+        rule_set,
+        role.delegate_args(authorization_data),
+    )
+}
+
+/// Revokes the token delegate of a programmable NFT.
+///
+/// Takes the same accounts as [`delegate_pnft_simple`]; `role` must be the role
+/// the delegate was approved with.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// # use spl_simplified::simplespl::DelegatePnft;
+/// use spl_simplified::simplespl::{revoke_pnft_simple, PnftDelegateRole};
+///
+/// # fn example<'info>(
+/// #     delegate_accounts: DelegatePnft<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// revoke_pnft_simple(delegate_accounts, PnftDelegateRole::Staking, &[signer_seeds]).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub fn revoke_pnft_simple<'info>(
+    accounts: DelegatePnft<'info>,
+    role: PnftDelegateRole,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let (rule_set, rule_accounts) = accounts.assert_pdas()?;
+
+    let mut remaining_accounts = vec![
+        accounts.edition.clone(),
+        accounts.token_record.clone(),
+        accounts.token.clone(),
+    ];
+    remaining_accounts.extend(rule_accounts);
+
+    msg!("revoke_pnft_simple: revoke");
+    revoke_v1(
+        CpiContext::new_with_signer(
+            accounts.token_metadata_program,
+            RevokeV1 {
+                delegate: accounts.delegate,
+                metadata: accounts.metadata,
+                mint: accounts.mint,
+                authority: accounts.authority,
+                payer: accounts.payer,
+                system_program: accounts.system_program,
+                sysvar_instructions: accounts.sysvar_instructions,
+                spl_token_program: accounts.token_program,
+            },
+            signer_seeds,
+        )
+        .with_remaining_accounts(remaining_accounts),
+        None,
+        Some(accounts.edition.key()),
+        Some(accounts.token_record.key()),
+        Some(accounts.token.key()),
+        rule_set,
+        role.revoke_args(),
+    )
+}
+
+/// Accounts used by [`lock_pnft_simple`] and [`unlock_pnft_simple`].
+pub struct LockPnft<'info> {
+    pub payer: AccountInfo<'info>,
+    /// The utility, staking or locked transfer delegate of `token`.
+    pub authority: AccountInfo<'info>,
+    pub token_owner: AccountInfo<'info>,
+    /// The token account holding the pNFT.
+    pub token: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    /// The metadata PDA of `mint`.
+    pub metadata: AccountInfo<'info>,
+    /// The master edition or edition PDA of `mint`.
+    pub edition: AccountInfo<'info>,
+    /// The token record PDA of `token`.
+    pub token_record: AccountInfo<'info>,
+    /// The rule set named by the metadata, if it has one.
+    pub authorization_rules: Option<AccountInfo<'info>>,
+    /// The token-auth-rules program, needed with `authorization_rules`.
+    pub authorization_rules_program: Option<AccountInfo<'info>>,
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> LockPnft<'info> {
+    fn assert_pdas(&self) -> Result<(Option<Pubkey>, Vec<AccountInfo<'info>>)> {
+        let rules = assert_pnft_accounts(
+            &self.mint,
+            &self.metadata,
+            &self.edition,
+            &self.sysvar_instructions,
+            self.authorization_rules.as_ref(),
+            self.authorization_rules_program.as_ref(),
+        )?;
+        assert_token_record(&self.token_record, self.mint.key, self.token.key)?;
+        Ok(rules)
+    }
+
+    fn remaining_accounts(
+        &self,
+        rule_accounts: Vec<AccountInfo<'info>>,
+    ) -> Vec<AccountInfo<'info>> {
+        let mut remaining_accounts = vec![
+            self.token_owner.clone(),
+            self.edition.clone(),
+            self.token_record.clone(),
+        ];
+        remaining_accounts.extend(rule_accounts);
+        remaining_accounts
+    }
+}
+
+/// Locks a programmable NFT in its owner's wallet, e.g. while it is staked.
+///
+/// Only a utility, staking or locked transfer delegate can lock a pNFT. The
+/// accounts are checked like in [`delegate_pnft_simple`].
+///
+/// # Arguments
+///
+/// * `accounts` - The accounts of the lock, see [`LockPnft`].
+/// * `authorization_data` - The payload evaluated against the rule set, if any.
+/// * `signer_seeds` - The seeds of every PDA signing, e.g. the delegate.
 ///
 /// # Example
 ///
-/// ```rust
-/// use simplespl::mint_simple;
-/// use anchor_lang::solana_program::account_info::AccountInfo;
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// # use spl_simplified::simplespl::LockPnft;
+/// use spl_simplified::simplespl::lock_pnft_simple;
 ///
-/// let result = mint_simple(
-///     "TokenName".to_string(),
-///     "TKN".to_string(),
-///     "https://example.com/token-metadata".to_string(),
-///     500, // 5% seller fee
-///     payer_account_info,
-///     token_metadata_program_info,
-///     update_authority_info,
-///     metadata_account_info,
-///     mint_authority_info,
-///     system_program_info,
-///     rent_sysvar_info,
-///     token_program_info,
-///     mint_account_info,
-///     to_account_info,
-///     owner_account_info,
-///     &[&signer_seeds],
-///     1000 // Mint 1000 tokens
-/// ).unwrap();
+/// # fn example<'info>(
+/// #     lock_accounts: LockPnft<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// lock_pnft_simple(lock_accounts, None, &[signer_seeds]).unwrap();
+/// # Ok(())
+/// # }
 /// ```
-pub fn mint_simple<'info>(
-    token_name: String,
-    token_symbol: String,
-    token_uri: String,
-    token_tax: u16,
-    payer: AccountInfo<'info>,
-    token_metadata_program: AccountInfo<'info>,
-    update_authority: AccountInfo<'info>,
-    metadata: AccountInfo<'info>,
-    mint_authority: AccountInfo<'info>,
-    system_program: AccountInfo<'info>,
-    rent: AccountInfo<'info>,
-    token_program: AccountInfo<'info>,
-    mint: AccountInfo<'info>,
-    to: AccountInfo<'info>,
-    owner: AccountInfo<'info>,
-    signer_seeds: &[&[u8]],
-    amount: u64,
+pub fn lock_pnft_simple<'info>(
+    accounts: LockPnft<'info>,
+    authorization_data: Option<AuthorizationData>,
+    signer_seeds: &[&[&[u8]]],
 ) -> Result<()> {
-    metadata_thing(
-        token_name.clone(),
-        token_symbol.clone(),
-        token_uri.clone(),
-        token_tax.clone(),
-        payer,
-        token_metadata_program,
-        update_authority,
-        mint.clone(),
-        metadata,
-        mint_authority,
-        system_program,
-        rent,
-        &[&signer_seeds],
-    )?;
+    let (rule_set, rule_accounts) = accounts.assert_pdas()?;
+    let remaining_accounts = accounts.remaining_accounts(rule_accounts);
 
-    let ix = spl_token::instruction::mint_to(
-        &spl_token::ID,
-        &mint.key(),
-        &to.key(),
-        &owner.key(),
-        &[],
-        amount,
-    )?;
+    msg!("lock_pnft_simple: lock");
+    lock_v1(
+        CpiContext::new_with_signer(
+            accounts.token_metadata_program,
+            LockV1 {
+                authority: accounts.authority,
+                token: accounts.token,
+                mint: accounts.mint,
+                metadata: accounts.metadata,
+                payer: accounts.payer,
+                system_program: accounts.system_program,
+                sysvar_instructions: accounts.sysvar_instructions,
+                spl_token_program: accounts.token_program,
+            },
+            signer_seeds,
+        )
+        .with_remaining_accounts(remaining_accounts),
+        Some(accounts.token_owner.key()),
+        Some(accounts.edition.key()),
+        Some(accounts.token_record.key()),
+        rule_set,
+        LockArgs::V1 { authorization_data },
+    )
+}
 
-    invoke_signed(
-        &ix,
-        &[mint.clone(), to.clone(), owner.clone(), token_program],
-        &[signer_seeds],
+/// Unlocks a programmable NFT locked with [`lock_pnft_simple`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// # use spl_simplified::simplespl::LockPnft;
+/// use spl_simplified::simplespl::unlock_pnft_simple;
+///
+/// # fn example<'info>(
+/// #     lock_accounts: LockPnft<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// unlock_pnft_simple(lock_accounts, None, &[signer_seeds]).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub fn unlock_pnft_simple<'info>(
+    accounts: LockPnft<'info>,
+    authorization_data: Option<AuthorizationData>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let (rule_set, rule_accounts) = accounts.assert_pdas()?;
+    let remaining_accounts = accounts.remaining_accounts(rule_accounts);
+
+    msg!("unlock_pnft_simple: unlock");
+    unlock_v1(
+        CpiContext::new_with_signer(
+            accounts.token_metadata_program,
+            UnlockV1 {
+                authority: accounts.authority,
+                token: accounts.token,
+                mint: accounts.mint,
+                metadata: accounts.metadata,
+                payer: accounts.payer,
+                system_program: accounts.system_program,
+                sysvar_instructions: accounts.sysvar_instructions,
+                spl_token_program: accounts.token_program,
+            },
+            signer_seeds,
+        )
+        .with_remaining_accounts(remaining_accounts),
+        Some(accounts.token_owner.key()),
+        Some(accounts.edition.key()),
+        Some(accounts.token_record.key()),
+        rule_set,
+        UnlockArgs::V1 { authorization_data },
     )
-    .map_err(Into::into)
+}
+
+fn read_metadata(account: &AccountInfo) -> Result<Metadata> {
+    let data = account.try_borrow_data()?;
+    Metadata::safe_deserialize(&data).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
+
+fn assert_collection_pdas(
+    collection_mint: &AccountInfo,
+    collection_metadata: &AccountInfo,
+    collection_master_edition: Option<&AccountInfo>,
+    collection_authority: &AccountInfo,
+    collection_authority_record: Option<&AccountInfo>,
+) -> Result<()> {
+    assert_metadata(collection_metadata, collection_mint.key)?;
+    if let Some(master_edition) = collection_master_edition {
+        assert_master_edition(master_edition, collection_mint.key)?;
+    }
+    if let Some(record) = collection_authority_record {
+        assert_collection_authority_record(record, collection_mint.key, collection_authority.key)?;
+    }
+    Ok(())
+}
+
+/// Checks the accounts shared by the pNFT flows and returns the rule set of the
+/// metadata together with the rule set accounts to pass to the CPI.
+fn assert_pnft_accounts<'info>(
+    mint: &AccountInfo,
+    metadata: &AccountInfo,
+    edition: &AccountInfo,
+    sysvar_instructions: &AccountInfo,
+    authorization_rules: Option<&AccountInfo<'info>>,
+    authorization_rules_program: Option<&AccountInfo<'info>>,
+) -> Result<(Option<Pubkey>, Vec<AccountInfo<'info>>)> {
+    assert_metadata(metadata, mint.key)?;
+    assert_master_edition(edition, mint.key)?;
+    if *sysvar_instructions.key != sysvar::instructions::ID {
+        return Err(SplSimplifiedError::InvalidSysvarInstructions.into());
+    }
+
+    let metadata = read_metadata(metadata)?;
+    if !matches!(
+        metadata.token_standard,
+        Some(TokenStandard::ProgrammableNonFungible)
+            | Some(TokenStandard::ProgrammableNonFungibleEdition)
+    ) {
+        return Err(SplSimplifiedError::NotProgrammable.into());
+    }
+    let rule_set = match metadata.programmable_config {
+        Some(ProgrammableConfig::V1 {
+            rule_set: Some(rule_set),
+        }) => rule_set,
+        _ => return Ok((None, Vec::new())),
+    };
+
+    let (Some(rules), Some(rules_program)) = (authorization_rules, authorization_rules_program)
+    else {
+        msg!(
+            "pNFT: rule set {} needs `authorization_rules` and its program",
+            rule_set
+        );
+        return Err(SplSimplifiedError::MissingAccount.into());
+    };
+    if *rules.key != rule_set {
+        return Err(SplSimplifiedError::RuleSetMismatch.into());
+    }
+    if *rules_program.key != AUTH_RULES_PROGRAM_ID {
+        return Err(ErrorCode::InvalidProgramId.into());
+    }
+    Ok((Some(rule_set), vec![rules.clone(), rules_program.clone()]))
 }
 
 /// Creates metadata for a token using the `mpl_token_metadata` program.
 ///
 /// This function sets up the metadata for a token, including its name, symbol, URI,
 /// seller fee, and creator information. It also uses the provided `signer_seed` for
-/// signing the metadata creation transaction.
+/// signing the metadata creation transaction. When `token_data` has no creators, the
+/// update authority is written as the sole, verified creator; the resulting data is
+/// validated before anything is invoked.
 ///
 /// # Arguments
 ///
-/// * `token_name` - The name of the token.
-/// * `token_symbol` - The symbol of the token.
-/// * `token_uri` - A URI that points to the token metadata.
-/// * `token_tax` - The seller fee in basis points (bps).
+/// * `token_data` - The name, symbol, URI, seller fee and optional fields of the metadata.
 /// * `payer` - The account paying for the transaction.
 /// * `token_metadata_program` - The token metadata program account.
 /// * `update_authority` - The account authorized to update the metadata.
@@ -136,15 +2452,18 @@ pub fn mint_simple<'info>(
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,ignore
 /// use simplespl::metadata_thing;
 /// use anchor_lang::solana_program::account_info::AccountInfo;
 ///
 /// metadata_thing(
-///     "TokenName".to_string(),
-///     "TKN".to_string(),
-///     "https://example.com/token-metadata".to_string(),
-///     500, // 5% seller fee
+///     SimpleMetadata {
+///         name: "TokenName".to_string(),
+///         symbol: "TKN".to_string(),
+///         uri: "https://example.com/token-metadata".to_string(),
+///         seller_fee_basis_points: 500, // 5% seller fee
+///         ..Default::default()
+///     },
 ///     payer_account_info,
 ///     token_metadata_program_info,
 ///     update_authority_info,
@@ -156,11 +2475,9 @@ pub fn mint_simple<'info>(
 ///     &[&signer_seed],
 /// ).unwrap();
 /// ```
+#[allow(clippy::too_many_arguments)]
 fn metadata_thing<'info>(
-    token_name: String,
-    token_symbol: String,
-    token_uri: String,
-    token_tax: u16,
+    mut token_data: SimpleMetadata,
     payer: AccountInfo<'info>,
     token_metadata_program: AccountInfo<'info>,
     update_authority: AccountInfo<'info>,
@@ -171,23 +2488,26 @@ fn metadata_thing<'info>(
     rent: AccountInfo<'info>,
     signer_seed: &[&[&[u8]]],
 ) -> Result<()> {
-    let mut creators: Vec<Creator> = Vec::<Creator>::new();
-    let creator: Creator = Creator {
-        address: mint.key(),
-        verified: true,
-        share: 100,
-    };
-
-    creators.push(creator);
+    token_data.creators.get_or_insert_with(|| {
+        vec![Creator {
+            address: *update_authority.key,
+            verified: true,
+            share: 100,
+        }]
+    });
+    token_data.validate(update_authority.key)?;
 
-    let token_data: DataV2 = DataV2 {
-        name: token_name.clone(),
-        symbol: token_symbol,
-        uri: token_uri,
-        seller_fee_basis_points: token_tax,
-        creators: Some(creators),
-        collection: None,
-        uses: None,
+    let data: DataV2 = DataV2 {
+        name: token_data.name,
+        symbol: token_data.symbol,
+        uri: token_data.uri,
+        seller_fee_basis_points: token_data.seller_fee_basis_points,
+        creators: token_data.creators,
+        collection: token_data.collection.map(|key| Collection {
+            verified: false,
+            key,
+        }),
+        uses: token_data.uses,
     };
 
     let metadata_ctx = CpiContext::new_with_signer(
@@ -204,66 +2524,101 @@ fn metadata_thing<'info>(
         signer_seed,
     );
 
-    create_metadata_accounts_v3(metadata_ctx, token_data, false, true, None).map_err(Into::into)
+    create_metadata_accounts_v3(
+        metadata_ctx,
+        data,
+        token_data.is_mutable,
+        true,
+        token_data.collection_details,
+    )
 }
 
 /// Transfers SPL tokens from one account to another.
 ///
-/// This function facilitates the transfer of SPL tokens between accounts
-/// on the Solana blockchain. It uses the `spl_token::instruction::transfer` function
-/// to create the transfer instruction, and signs the transaction using the provided
-/// `signer_seeds`.
+/// The transfer is done with `transfer_checked`, using the decimals read from
+/// `mint`, so the token program rejects it if `mint` is not the mint of the
+/// accounts. Before invoking, `source` and `destination` are checked to be token
+/// accounts of `mint`. Works with the legacy Token program and Token-2022.
+///
+/// When `mint` is the native mint and `source` is a system account rather than a
+/// token account, SOL is wrapped on demand instead: `amount` lamports are moved
+/// from `source` into the destination wrapped SOL account, which is then synced.
+/// `source` has to sign in that case, and the System Program must be part of the
+/// transaction; `authority` is not used.
 ///
 /// # Arguments
 ///
 /// * `mint` - The mint account of the token.
-/// * `token_program_id` - The token program account (usually `spl_token`).
-/// * `source_pubkey` - The source account's public key from which tokens will be transferred.
-/// * `destination_pubkey` - The destination account's `AccountInfo`.
-/// * `authority_pubkey` - The authority account that will sign the transfer.
+/// * `token_program` - The token program that owns the token accounts.
+/// * `source` - The token account tokens are transferred from.
+/// * `destination` - The token account tokens are transferred to.
+/// * `authority` - The owner or delegate of `source`.
 /// * `amount` - The amount of tokens to transfer.
 /// * `signer_seeds` - A slice of slices of seeds for signing the transaction.
 ///
 /// # Example
 ///
-/// ```rust
-/// use simplespl::transfer_simple;
-/// use anchor_lang::solana_program::account_info::AccountInfo;
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// use spl_simplified::simplespl::transfer_simple;
 ///
+/// # fn example<'info>(
+/// #     mint_account_info: AccountInfo<'info>,
+/// #     token_program_account_info: AccountInfo<'info>,
+/// #     source_account_info: AccountInfo<'info>,
+/// #     destination_account_info: AccountInfo<'info>,
+/// #     authority_account_info: AccountInfo<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
 /// transfer_simple(
 ///     mint_account_info,
 ///     token_program_account_info,
-///     source_pubkey,
+///     source_account_info,
 ///     destination_account_info,
 ///     authority_account_info,
 ///     500, // Transfer 500 tokens
-///     &[&signer_seeds],
+///     signer_seeds,
 /// ).unwrap();
+/// # Ok(())
+/// # }
 /// ```
 pub fn transfer_simple<'info>(
     mint: AccountInfo<'info>,
-    token_program_id: AccountInfo<'info>,
-    source_pubkey: Pubkey,
-    destination_pubkey: AccountInfo<'info>,
-    authority_pubkey: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    source: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
     amount: u64,
     signer_seeds: &[&[u8]],
 ) -> Result<()> {
-    let ix = spl_token::instruction::transfer(
-        &token_program_id.key(),
-        &source_pubkey,
-        &destination_pubkey.key(),
-        &authority_pubkey.key(),
-        &[],
-        amount,
-    )?;
+    if accessor::mint(&destination)? != *mint.key {
+        return Err(SplSimplifiedError::TransferMintMismatch.into());
+    }
 
-    invoke_signed(
-        &ix,
-        &[mint, destination_pubkey, authority_pubkey, token_program_id],
-        &[signer_seeds],
+    if is_native_mint(mint.key) && *source.owner == system_program::ID {
+        return wrap_lamports(source, destination, token_program, amount, signer_seeds);
+    }
+
+    if accessor::mint(&source)? != *mint.key {
+        return Err(SplSimplifiedError::TransferMintMismatch.into());
+    }
+    let decimals = accessor::decimals(&mint)?;
+
+    msg!("transfer_simple: transfer_checked");
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program,
+            TransferChecked {
+                from: source,
+                mint,
+                to: destination,
+                authority,
+            },
+            &[signer_seeds],
+        ),
+        amount,
+        decimals,
     )
-    .map_err(Into::into)
 }
 
 /// Burns SPL tokens from an account.
@@ -282,18 +2637,27 @@ pub fn transfer_simple<'info>(
 ///
 /// # Example
 ///
-/// ```rust
-/// use simplespl::burn_simple;
-/// use anchor_lang::solana_program::account_info::AccountInfo;
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// use spl_simplified::simplespl::burn_simple;
 ///
+/// # fn example<'info>(
+/// #     mint_account_info: AccountInfo<'info>,
+/// #     token_program_id: AccountInfo<'info>,
+/// #     source_account_info: AccountInfo<'info>,
+/// #     authority_account_info: AccountInfo<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
 /// burn_simple(
 ///     mint_account_info,
 ///     token_program_id,
 ///     source_account_info,
 ///     authority_account_info,
 ///     1000, // Burn 1000 tokens
-///     &[&signer_seeds],
+///     signer_seeds,
 /// ).unwrap();
+/// # Ok(())
+/// # }
 /// ```
 pub fn burn_simple<'info>(
     mint: AccountInfo<'info>,
@@ -303,6 +2667,7 @@ pub fn burn_simple<'info>(
     amount: u64,
     signer_seeds: &[&[u8]],
 ) -> Result<()> {
+    msg!("burn_simple: burn");
     let ix = spl_token::instruction::burn(
         &token_program_id.key(),
         &source_pubkey.key(),
@@ -323,3 +2688,651 @@ pub fn burn_simple<'info>(
     )
     .map_err(Into::into)
 }
+
+/// Transfers tokens of a Token-2022 mint that charges a transfer fee.
+///
+/// The decimals are read from the mint and the fee is computed from the mint's
+/// `TransferFeeConfig` for the current epoch, so the instruction always matches
+/// what the token program expects. The destination receives `amount` minus the
+/// fee; the fee stays withheld in the destination account until it is harvested.
+/// Mints without a transfer fee are transferred with a zero fee.
+///
+/// # Arguments
+///
+/// * `mint` - The mint account of the token.
+/// * `token_program` - The Token-2022 program account.
+/// * `source` - The token account tokens are transferred from.
+/// * `destination` - The token account tokens are transferred to.
+/// * `authority` - The owner or delegate of `source`.
+/// * `amount` - The amount of tokens to transfer, before fees.
+/// * `signer_seeds` - A slice of slices of seeds for signing the transaction.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// use spl_simplified::simplespl::transfer_with_fee_simple;
+///
+/// # fn example<'info>(
+/// #     mint_account_info: AccountInfo<'info>,
+/// #     token_2022_program_info: AccountInfo<'info>,
+/// #     source_account_info: AccountInfo<'info>,
+/// #     destination_account_info: AccountInfo<'info>,
+/// #     authority_account_info: AccountInfo<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// transfer_with_fee_simple(
+///     mint_account_info,
+///     token_2022_program_info,
+///     source_account_info,
+///     destination_account_info,
+///     authority_account_info,
+///     500,
+///     signer_seeds,
+/// ).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub fn transfer_with_fee_simple<'info>(
+    mint: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    source: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let decimals = {
+        let data = mint.try_borrow_data()?;
+        StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?
+            .base
+            .decimals
+    };
+    let fee = calculate_transfer_fee(&mint, amount)?;
+
+    msg!("transfer_with_fee_simple: transfer_checked_with_fee");
+    transfer_checked_with_fee(
+        CpiContext::new_with_signer(
+            token_program,
+            TransferCheckedWithFee {
+                source,
+                mint,
+                destination,
+                authority,
+            },
+            &[signer_seeds],
+        ),
+        amount,
+        decimals,
+        fee,
+    )
+}
+
+/// Wraps SOL into a native (wrapped SOL) token account.
+///
+/// Moves `lamports` from `payer` into `token_account` with a system transfer and then
+/// calls `sync_native`, so the token balance matches the deposited lamports. If
+/// `token_account` does not exist yet, it is created as the associated token account of
+/// `owner` for `native_mint`, paid by `payer`; an existing account can be any native
+/// token account. Works with the legacy Token program and Token-2022. Any other
+/// mint fails with `SplSimplifiedError::NotNativeMint`.
+///
+/// # Arguments
+///
+/// * `payer` - The system account the lamports (and the rent of a new account) are taken from.
+/// * `owner` - The owner of `token_account`.
+/// * `token_account` - The native token account receiving the lamports.
+/// * `native_mint` - The native mint of `token_program`.
+/// * `token_program` - The token program that owns `token_account`.
+/// * `associated_token_program` - The associated token account program.
+/// * `system_program` - The system program account.
+/// * `lamports` - The amount of lamports to wrap.
+/// * `signer_seeds` - A slice of slices of seeds for signing the transaction.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// use spl_simplified::simplespl::wrap_sol;
+///
+/// # fn example<'info>(
+/// #     payer_account_info: AccountInfo<'info>,
+/// #     owner_account_info: AccountInfo<'info>,
+/// #     wsol_account_info: AccountInfo<'info>,
+/// #     native_mint_account_info: AccountInfo<'info>,
+/// #     token_program_account_info: AccountInfo<'info>,
+/// #     associated_token_program_account_info: AccountInfo<'info>,
+/// #     system_program_account_info: AccountInfo<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// wrap_sol(
+///     payer_account_info,
+///     owner_account_info,
+///     wsol_account_info,
+///     native_mint_account_info,
+///     token_program_account_info,
+///     associated_token_program_account_info,
+///     system_program_account_info,
+///     1_000_000_000, // Wrap 1 SOL
+///     signer_seeds,
+/// ).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn wrap_sol<'info>(
+    payer: AccountInfo<'info>,
+    owner: AccountInfo<'info>,
+    token_account: AccountInfo<'info>,
+    native_mint: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    associated_token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    lamports: u64,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    if !is_native_mint(native_mint.key) {
+        return Err(SplSimplifiedError::NotNativeMint.into());
+    }
+
+    if token_account.data_is_empty() {
+        msg!("wrap_sol: create associated token account");
+        create_idempotent(CpiContext::new(
+            associated_token_program,
+            Create {
+                payer: payer.clone(),
+                associated_token: token_account.clone(),
+                authority: owner,
+                mint: native_mint,
+                system_program,
+                token_program: token_program.clone(),
+            },
+        ))?;
+    }
+
+    wrap_lamports(payer, token_account, token_program, lamports, signer_seeds)
+}
+
+/// Unwraps SOL by closing a native token account.
+///
+/// Closing the account sends its whole lamport balance, wrapped tokens and rent
+/// included, to `destination`.
+///
+/// # Arguments
+///
+/// * `token_account` - The native token account to close.
+/// * `destination` - The account receiving the lamports.
+/// * `authority` - The owner of `token_account`.
+/// * `token_program` - The token program that owns `token_account`.
+/// * `signer_seeds` - A slice of slices of seeds for signing the transaction.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use anchor_lang::prelude::*;
+/// use spl_simplified::simplespl::unwrap_sol;
+///
+/// # fn example<'info>(
+/// #     wsol_account_info: AccountInfo<'info>,
+/// #     destination_account_info: AccountInfo<'info>,
+/// #     authority_account_info: AccountInfo<'info>,
+/// #     token_program_account_info: AccountInfo<'info>,
+/// #     signer_seeds: &[&[u8]],
+/// # ) -> Result<()> {
+/// unwrap_sol(
+///     wsol_account_info,
+///     destination_account_info,
+///     authority_account_info,
+///     token_program_account_info,
+///     signer_seeds,
+/// ).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+pub fn unwrap_sol<'info>(
+    token_account: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    msg!("unwrap_sol: close account");
+    close_account(CpiContext::new_with_signer(
+        token_program,
+        CloseAccount {
+            account: token_account,
+            destination,
+            authority,
+        },
+        &[signer_seeds],
+    ))
+}
+
+fn is_native_mint(mint: &Pubkey) -> bool {
+    *mint == spl_token::native_mint::ID || *mint == spl_token_2022::native_mint::ID
+}
+
+// The lamports have to land before `sync_native`, otherwise the token balance
+// is synced against the old lamport balance.
+fn wrap_lamports<'info>(
+    from: AccountInfo<'info>,
+    token_account: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    lamports: u64,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let ix = solana_program::system_instruction::transfer(from.key, token_account.key, lamports);
+    msg!("wrap SOL: transfer lamports");
+    invoke_signed(&ix, &[from, token_account.clone()], &[signer_seeds])?;
+
+    msg!("wrap SOL: sync_native");
+    sync_native(CpiContext::new(
+        token_program,
+        SyncNative {
+            account: token_account,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::{find_master_edition, find_metadata};
+    use crate::stubs;
+    use anchor_lang::error::ErrorOrigin;
+    use anchor_lang::solana_program::program_option::COption;
+    use anchor_lang::solana_program::system_program;
+    use anchor_lang::AccountSerialize;
+    use mpl_token_metadata::types::Key;
+
+    // The accounts handed to the helpers are leaked, so they outlive the helpers
+    // without threading lifetimes through every test.
+    fn account(key: Pubkey) -> AccountInfo<'static> {
+        account_with(key, Pubkey::default(), Vec::new())
+    }
+
+    fn account_with(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(key)),
+            false,
+            false,
+            Box::leak(Box::new(0)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            false,
+            0,
+        )
+    }
+
+    fn packed<T: Pack>(state: T) -> Vec<u8> {
+        let mut data = vec![0u8; T::LEN];
+        state.pack_into_slice(&mut data);
+        data
+    }
+
+    fn mint_account(key: Pubkey, decimals: u8) -> AccountInfo<'static> {
+        let mint = spl_token::state::Mint {
+            mint_authority: COption::Some(Pubkey::new_unique()),
+            supply: 1_000_000,
+            decimals,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        account_with(key, spl_token::ID, packed(mint))
+    }
+
+    fn token_account(mint: Pubkey) -> AccountInfo<'static> {
+        let account = spl_token::state::Account {
+            mint,
+            owner: Pubkey::new_unique(),
+            amount: 1_000,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        };
+        account_with(Pubkey::new_unique(), spl_token::ID, packed(account))
+    }
+
+    fn account_name(error: &Error) -> Option<&str> {
+        match error {
+            Error::AnchorError(error) => match &error.error_origin {
+                Some(ErrorOrigin::AccountName(name)) => Some(name),
+                _ => None,
+            },
+            Error::ProgramError(_) => None,
+        }
+    }
+
+    #[test]
+    fn transfer_simple_rejects_accounts_of_other_mints() {
+        let mint = Pubkey::new_unique();
+        let transfer = |source, destination| {
+            stubs::invoked(|| {
+                transfer_simple(
+                    mint_account(mint, 6),
+                    account(spl_token::ID),
+                    source,
+                    destination,
+                    account(Pubkey::new_unique()),
+                    100,
+                    &[],
+                )
+            })
+        };
+
+        let (result, invoked) = transfer(token_account(mint), token_account(Pubkey::new_unique()));
+        assert_eq!(
+            result.unwrap_err(),
+            SplSimplifiedError::TransferMintMismatch.into()
+        );
+        assert!(invoked.is_empty());
+
+        let (result, invoked) = transfer(token_account(Pubkey::new_unique()), token_account(mint));
+        assert_eq!(
+            result.unwrap_err(),
+            SplSimplifiedError::TransferMintMismatch.into()
+        );
+        assert!(invoked.is_empty());
+    }
+
+    #[test]
+    fn transfer_simple_wraps_lamports_of_system_accounts() {
+        let mint = spl_token::native_mint::ID;
+        let source = Pubkey::new_unique();
+        let destination = token_account(mint);
+        let destination_key = *destination.key;
+
+        let (result, invoked) = stubs::invoked(|| {
+            transfer_simple(
+                mint_account(mint, 9),
+                account(spl_token::ID),
+                account_with(source, system_program::ID, Vec::new()),
+                destination,
+                account(Pubkey::new_unique()),
+                100,
+                &[],
+            )
+        });
+        result.unwrap();
+        assert_eq!(
+            invoked,
+            vec![
+                solana_program::system_instruction::transfer(&source, &destination_key, 100),
+                spl_token::instruction::sync_native(&spl_token::ID, &destination_key).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn transfer_simple_transfers_native_token_accounts() {
+        let mint = spl_token::native_mint::ID;
+        let (source, destination) = (token_account(mint), token_account(mint));
+        let (source_key, destination_key) = (*source.key, *destination.key);
+        let authority = Pubkey::new_unique();
+
+        let (result, invoked) = stubs::invoked(|| {
+            transfer_simple(
+                mint_account(mint, 9),
+                account(spl_token::ID),
+                source,
+                destination,
+                account(authority),
+                100,
+                &[],
+            )
+        });
+        result.unwrap();
+        let transfer = spl_token::instruction::transfer_checked(
+            &spl_token::ID,
+            &source_key,
+            &mint,
+            &destination_key,
+            &authority,
+            &[],
+            100,
+            9,
+        )
+        .unwrap();
+        assert_eq!(invoked, vec![transfer]);
+    }
+
+    fn simple_metadata() -> SimpleMetadata {
+        SimpleMetadata {
+            name: "Token".to_string(),
+            symbol: "TKN".to_string(),
+            uri: "https://example.com/token.json".to_string(),
+            seller_fee_basis_points: 500,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn simple_metadata_rejects_invalid_uses() {
+        let update_authority = Pubkey::new_unique();
+        let uses = |use_method, remaining, total| SimpleMetadata {
+            uses: Some(Uses {
+                use_method,
+                remaining,
+                total,
+            }),
+            ..simple_metadata()
+        };
+
+        assert!(uses(UseMethod::Multiple, 3, 5)
+            .validate(&update_authority)
+            .is_ok());
+        assert_eq!(
+            uses(UseMethod::Multiple, 6, 5)
+                .validate(&update_authority)
+                .unwrap_err(),
+            SplSimplifiedError::InvalidUses.into()
+        );
+        assert_eq!(
+            uses(UseMethod::Single, 2, 2)
+                .validate(&update_authority)
+                .unwrap_err(),
+            SplSimplifiedError::InvalidUses.into()
+        );
+    }
+
+    #[test]
+    fn simple_metadata_only_verifies_the_update_authority() {
+        let update_authority = Pubkey::new_unique();
+        let creators = |address, verified| SimpleMetadata {
+            creators: Some(vec![Creator {
+                address,
+                verified,
+                share: 100,
+            }]),
+            ..simple_metadata()
+        };
+
+        assert!(creators(update_authority, true)
+            .validate(&update_authority)
+            .is_ok());
+        assert!(creators(Pubkey::new_unique(), false)
+            .validate(&update_authority)
+            .is_ok());
+        assert_eq!(
+            creators(Pubkey::new_unique(), true)
+                .validate(&update_authority)
+                .unwrap_err(),
+            SplSimplifiedError::CannotVerifyAnotherCreator.into()
+        );
+    }
+
+    // A `SimpleMint` with every account set, minus the ones removed by the tests.
+    fn simple_mint<'a>() -> SimpleMint<'a, 'static> {
+        SimpleMint::new()
+            .payer(account(Pubkey::new_unique()))
+            .mint(account(Pubkey::new_unique()))
+            .mint_authority(account(Pubkey::new_unique()))
+            .token_program(account(spl_token::ID))
+            .system_program(account(system_program::ID))
+            .rent(account(sysvar::rent::ID))
+            .token_metadata_program(account(mpl_token_metadata::ID))
+            .metadata_account(account(Pubkey::new_unique()))
+            .update_authority(account(Pubkey::new_unique()))
+            .associated_token_program(account(spl_associated_token_account::ID))
+            .to(account(Pubkey::new_unique()))
+            .owner(account(Pubkey::new_unique()))
+    }
+
+    #[test]
+    fn simple_mint_checks_the_accounts_of_every_step_first() {
+        let all_steps = |builder: SimpleMint<'static, 'static>| {
+            builder
+                .metadata(simple_metadata())
+                .create_recipient_ata(true)
+                .amount(1_000)
+        };
+        let mut without_rent = all_steps(simple_mint());
+        without_rent.rent = None;
+        let mut without_associated_token_program = all_steps(simple_mint());
+        without_associated_token_program.associated_token_program = None;
+        // `to` is needed by the recipient ATA step too, so that step is left out.
+        let mut without_to = simple_mint().metadata(simple_metadata()).amount(1_000);
+        without_to.to = None;
+
+        let cases = [
+            (without_rent, "rent"),
+            (without_associated_token_program, "associated_token_program"),
+            (without_to, "to"),
+        ];
+        for (builder, missing) in cases {
+            let (result, invoked) = stubs::invoked(|| builder.invoke());
+            let error = result.unwrap_err();
+            assert_eq!(error, SplSimplifiedError::MissingAccount.into());
+            assert_eq!(account_name(&error), Some(missing));
+            assert!(invoked.is_empty());
+        }
+
+        let mut builder = simple_mint().amount(1_000);
+        builder.mint = None;
+        let error = builder.invoke().unwrap_err();
+        assert_eq!(account_name(&error), Some("mint"));
+    }
+
+    fn print_edition_accounts(supply: u64, max_supply: Option<u64>) -> PrintEdition<'static> {
+        let master_mint = Pubkey::new_unique();
+        let master_edition =
+            MasterEditionAccount::new(mpl_token_metadata::accounts::MasterEdition {
+                key: Key::MasterEditionV2,
+                supply,
+                max_supply,
+            });
+        let mut data = Vec::new();
+        master_edition.try_serialize(&mut data).unwrap();
+
+        PrintEdition {
+            payer: account(Pubkey::new_unique()),
+            new_mint: account(Pubkey::new_unique()),
+            new_mint_authority: account(Pubkey::new_unique()),
+            new_metadata: account(Pubkey::new_unique()),
+            new_edition: account(Pubkey::new_unique()),
+            new_metadata_update_authority: account(Pubkey::new_unique()),
+            recipient: account(Pubkey::new_unique()),
+            recipient_token_account: account(Pubkey::new_unique()),
+            master_mint: account(master_mint),
+            master_metadata: account(find_metadata(&master_mint).0),
+            master_edition: account_with(
+                find_master_edition(&master_mint).0,
+                mpl_token_metadata::ID,
+                data,
+            ),
+            master_token_account: account(Pubkey::new_unique()),
+            master_token_account_owner: account(Pubkey::new_unique()),
+            edition_marker: account(Pubkey::new_unique()),
+            token_program: account(spl_token::ID),
+            associated_token_program: account(spl_associated_token_account::ID),
+            token_metadata_program: account(mpl_token_metadata::ID),
+            system_program: account(system_program::ID),
+            rent: account(sysvar::rent::ID),
+        }
+    }
+
+    #[test]
+    fn print_edition_simple_stops_at_max_supply() {
+        for (supply, max_supply) in [(10, Some(10)), (u64::MAX, None)] {
+            let (result, invoked) = stubs::invoked(|| {
+                print_edition_simple(print_edition_accounts(supply, max_supply), &[])
+            });
+            assert_eq!(
+                result.unwrap_err(),
+                SplSimplifiedError::MaxEditionsMintedAlready.into()
+            );
+            assert!(invoked.is_empty());
+        }
+    }
+
+    #[test]
+    fn pnft_delegate_roles_map_to_args() {
+        let locked_address = Pubkey::new_unique();
+        let roles = [
+            (
+                PnftDelegateRole::Sale,
+                DelegateArgs::SaleV1 {
+                    amount: 1,
+                    authorization_data: None,
+                },
+                RevokeArgs::SaleV1,
+            ),
+            (
+                PnftDelegateRole::Transfer,
+                DelegateArgs::TransferV1 {
+                    amount: 1,
+                    authorization_data: None,
+                },
+                RevokeArgs::TransferV1,
+            ),
+            (
+                PnftDelegateRole::Utility,
+                DelegateArgs::UtilityV1 {
+                    amount: 1,
+                    authorization_data: None,
+                },
+                RevokeArgs::UtilityV1,
+            ),
+            (
+                PnftDelegateRole::Staking,
+                DelegateArgs::StakingV1 {
+                    amount: 1,
+                    authorization_data: None,
+                },
+                RevokeArgs::StakingV1,
+            ),
+            (
+                PnftDelegateRole::LockedTransfer { locked_address },
+                DelegateArgs::LockedTransferV1 {
+                    amount: 1,
+                    locked_address,
+                    authorization_data: None,
+                },
+                RevokeArgs::LockedTransferV1,
+            ),
+        ];
+
+        for (role, delegate_args, revoke_args) in roles {
+            assert_eq!(role.delegate_args(None), delegate_args);
+            assert_eq!(role.revoke_args(), revoke_args);
+        }
+    }
+
+    #[test]
+    fn wrap_sol_rejects_other_mints() {
+        let result = wrap_sol(
+            account(Pubkey::new_unique()),
+            account(Pubkey::new_unique()),
+            account(Pubkey::new_unique()),
+            account(Pubkey::new_unique()),
+            account(spl_token::ID),
+            account(spl_associated_token_account::ID),
+            account(system_program::ID),
+            1_000,
+            &[],
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            SplSimplifiedError::NotNativeMint.into()
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Syscall stubs shared by the unit tests.
+//!
+//! The `solana_program` syscall stubs are global to the test binary, so every
+//! test that invokes a program goes through the same stubs. They record the
+//! instructions invoked by the current thread, answer `GetAccountDataSize` the
+//! way the token program does and provide the default `Rent`; any other
+//! instruction succeeds without doing anything.
+
+use std::cell::RefCell;
+use std::sync::Once;
+
+use anchor_lang::solana_program::account_info::AccountInfo;
+use anchor_lang::solana_program::entrypoint::{ProgramResult, SUCCESS};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_lang::solana_program::rent::Rent;
+use spl_token_2022::extension::ExtensionType;
+use spl_token_2022::instruction::TokenInstruction;
+
+thread_local! {
+    static INVOKED: RefCell<Vec<Instruction>> = const { RefCell::new(Vec::new()) };
+    static RETURN_DATA: RefCell<Option<(Pubkey, Vec<u8>)>> = const { RefCell::new(None) };
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        _account_infos: &[AccountInfo],
+        _signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        INVOKED.with(|invoked| invoked.borrow_mut().push(instruction.clone()));
+
+        let is_token_program =
+            instruction.program_id == spl_token::ID || instruction.program_id == spl_token_2022::ID;
+        if !is_token_program {
+            return Ok(());
+        }
+        if let Ok(TokenInstruction::GetAccountDataSize { extension_types }) =
+            TokenInstruction::unpack(&instruction.data)
+        {
+            let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(
+                &extension_types,
+            )?;
+            RETURN_DATA.with(|data| {
+                *data.borrow_mut() =
+                    Some((instruction.program_id, (len as u64).to_le_bytes().to_vec()))
+            });
+        }
+        Ok(())
+    }
+
+    fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+        RETURN_DATA.with(|data| data.borrow().clone())
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        // `Rent::get` hands over a pointer to a `Rent`.
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        SUCCESS
+    }
+}
+
+/// Runs `f` and returns its result together with the instructions it invoked.
+pub(crate) fn invoked<R>(f: impl FnOnce() -> R) -> (R, Vec<Instruction>) {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        set_syscall_stubs(Box::new(Stubs));
+    });
+
+    INVOKED.with(|invoked| invoked.borrow_mut().clear());
+    RETURN_DATA.with(|data| *data.borrow_mut() = None);
+    let result = f();
+    (result, INVOKED.with(|invoked| invoked.take()))
+}
@@ -1,32 +1,37 @@
+use anchor_lang::error::ErrorCode;
 use anchor_lang::solana_program::account_info::AccountInfo;
 
+use anchor_lang::solana_program::program_error::ProgramError;
 use anchor_lang::solana_program::program_pack::Pack;
 use anchor_lang::solana_program::pubkey::Pubkey;
 use anchor_lang::{context::CpiContext, Accounts};
 use anchor_lang::{solana_program, Result};
-use std::ops::Deref;
+use std::cell::Ref;
+use std::io::Write;
+use std::ops::{Deref, DerefMut};
 
 pub use spl_token;
 pub use spl_token::ID;
 
+use crate::common::{multisig_signers, return_data};
+
 pub fn transfer<'info>(
     ctx: CpiContext<'_, '_, '_, 'info, Transfer<'info>>,
     amount: u64,
 ) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
     let ix = spl_token::instruction::transfer(
         &spl_token::ID,
         ctx.accounts.from.key,
         ctx.accounts.to.key,
         ctx.accounts.authority.key,
-        &[],
+        &signers,
         amount,
     )?;
-    solana_program::program::invoke_signed(
-        &ix,
-        &[ctx.accounts.from, ctx.accounts.to, ctx.accounts.authority],
-        ctx.signer_seeds,
-    )
-    .map_err(Into::into)
+    let mut account_infos = vec![ctx.accounts.from, ctx.accounts.to, ctx.accounts.authority];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
 }
 
 pub fn transfer_checked<'info>(
@@ -34,88 +39,84 @@ pub fn transfer_checked<'info>(
     amount: u64,
     decimals: u8,
 ) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
     let ix = spl_token::instruction::transfer_checked(
         &spl_token::ID,
         ctx.accounts.from.key,
         ctx.accounts.mint.key,
         ctx.accounts.to.key,
         ctx.accounts.authority.key,
-        &[],
+        &signers,
         amount,
         decimals,
     )?;
-    solana_program::program::invoke_signed(
-        &ix,
-        &[
-            ctx.accounts.from,
-            ctx.accounts.mint,
-            ctx.accounts.to,
-            ctx.accounts.authority,
-        ],
-        ctx.signer_seeds,
-    )
-    .map_err(Into::into)
+    let mut account_infos = vec![
+        ctx.accounts.from,
+        ctx.accounts.mint,
+        ctx.accounts.to,
+        ctx.accounts.authority,
+    ];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
 }
 
 pub fn mint_to<'info>(
     ctx: CpiContext<'_, '_, '_, 'info, MintTo<'info>>,
     amount: u64,
 ) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
     let ix = spl_token::instruction::mint_to(
         &spl_token::ID,
         ctx.accounts.mint.key,
         ctx.accounts.to.key,
         ctx.accounts.authority.key,
-        &[],
+        &signers,
         amount,
     )?;
-    solana_program::program::invoke_signed(
-        &ix,
-        &[ctx.accounts.to, ctx.accounts.mint, ctx.accounts.authority],
-        ctx.signer_seeds,
-    )
-    .map_err(Into::into)
+    let mut account_infos = vec![ctx.accounts.to, ctx.accounts.mint, ctx.accounts.authority];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
 }
 
 pub fn burn<'info>(ctx: CpiContext<'_, '_, '_, 'info, Burn<'info>>, amount: u64) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
     let ix = spl_token::instruction::burn(
         &spl_token::ID,
         ctx.accounts.from.key,
         ctx.accounts.mint.key,
         ctx.accounts.authority.key,
-        &[],
+        &signers,
         amount,
     )?;
-    solana_program::program::invoke_signed(
-        &ix,
-        &[ctx.accounts.from, ctx.accounts.mint, ctx.accounts.authority],
-        ctx.signer_seeds,
-    )
-    .map_err(Into::into)
+    let mut account_infos = vec![ctx.accounts.from, ctx.accounts.mint, ctx.accounts.authority];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
 }
 
 pub fn approve<'info>(
     ctx: CpiContext<'_, '_, '_, 'info, Approve<'info>>,
     amount: u64,
 ) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
     let ix = spl_token::instruction::approve(
         &spl_token::ID,
         ctx.accounts.to.key,
         ctx.accounts.delegate.key,
         ctx.accounts.authority.key,
-        &[],
+        &signers,
         amount,
     )?;
-    solana_program::program::invoke_signed(
-        &ix,
-        &[
-            ctx.accounts.to,
-            ctx.accounts.delegate,
-            ctx.accounts.authority,
-        ],
-        ctx.signer_seeds,
-    )
-    .map_err(Into::into)
+    let mut account_infos = vec![
+        ctx.accounts.to,
+        ctx.accounts.delegate,
+        ctx.accounts.authority,
+    ];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
 }
 
 pub fn approve_checked<'info>(
@@ -123,42 +124,40 @@ pub fn approve_checked<'info>(
     amount: u64,
     decimals: u8,
 ) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
     let ix = spl_token::instruction::approve_checked(
         &spl_token::ID,
         ctx.accounts.to.key,
         ctx.accounts.mint.key,
         ctx.accounts.delegate.key,
         ctx.accounts.authority.key,
-        &[],
+        &signers,
         amount,
         decimals,
     )?;
-    solana_program::program::invoke_signed(
-        &ix,
-        &[
-            ctx.accounts.to,
-            ctx.accounts.mint,
-            ctx.accounts.delegate,
-            ctx.accounts.authority,
-        ],
-        ctx.signer_seeds,
-    )
-    .map_err(Into::into)
+    let mut account_infos = vec![
+        ctx.accounts.to,
+        ctx.accounts.mint,
+        ctx.accounts.delegate,
+        ctx.accounts.authority,
+    ];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
 }
 
 pub fn revoke<'info>(ctx: CpiContext<'_, '_, '_, 'info, Revoke<'info>>) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
     let ix = spl_token::instruction::revoke(
         &spl_token::ID,
         ctx.accounts.source.key,
         ctx.accounts.authority.key,
-        &[],
+        &signers,
     )?;
-    solana_program::program::invoke_signed(
-        &ix,
-        &[ctx.accounts.source, ctx.accounts.authority],
-        ctx.signer_seeds,
-    )
-    .map_err(Into::into)
+    let mut account_infos = vec![ctx.accounts.source, ctx.accounts.authority];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
 }
 
 pub fn initialize_account<'info>(
@@ -201,65 +200,62 @@ pub fn initialize_account3<'info>(
 }
 
 pub fn close_account<'info>(ctx: CpiContext<'_, '_, '_, 'info, CloseAccount<'info>>) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
     let ix = spl_token::instruction::close_account(
         &spl_token::ID,
         ctx.accounts.account.key,
         ctx.accounts.destination.key,
         ctx.accounts.authority.key,
-        &[], // TODO: support multisig
+        &signers,
     )?;
-    solana_program::program::invoke_signed(
-        &ix,
-        &[
-            ctx.accounts.account,
-            ctx.accounts.destination,
-            ctx.accounts.authority,
-        ],
-        ctx.signer_seeds,
-    )
-    .map_err(Into::into)
+    let mut account_infos = vec![
+        ctx.accounts.account,
+        ctx.accounts.destination,
+        ctx.accounts.authority,
+    ];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
 }
 
 pub fn freeze_account<'info>(
     ctx: CpiContext<'_, '_, '_, 'info, FreezeAccount<'info>>,
 ) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
     let ix = spl_token::instruction::freeze_account(
         &spl_token::ID,
         ctx.accounts.account.key,
         ctx.accounts.mint.key,
         ctx.accounts.authority.key,
-        &[], // TODO: Support multisig signers.
+        &signers,
     )?;
-    solana_program::program::invoke_signed(
-        &ix,
-        &[
-            ctx.accounts.account,
-            ctx.accounts.mint,
-            ctx.accounts.authority,
-        ],
-        ctx.signer_seeds,
-    )
-    .map_err(Into::into)
+    let mut account_infos = vec![
+        ctx.accounts.account,
+        ctx.accounts.mint,
+        ctx.accounts.authority,
+    ];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
 }
 
 pub fn thaw_account<'info>(ctx: CpiContext<'_, '_, '_, 'info, ThawAccount<'info>>) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
     let ix = spl_token::instruction::thaw_account(
         &spl_token::ID,
         ctx.accounts.account.key,
         ctx.accounts.mint.key,
         ctx.accounts.authority.key,
-        &[], // TODO: Support multisig signers.
+        &signers,
     )?;
-    solana_program::program::invoke_signed(
-        &ix,
-        &[
-            ctx.accounts.account,
-            ctx.accounts.mint,
-            ctx.accounts.authority,
-        ],
-        ctx.signer_seeds,
-    )
-    .map_err(Into::into)
+    let mut account_infos = vec![
+        ctx.accounts.account,
+        ctx.accounts.mint,
+        ctx.accounts.authority,
+    ];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
 }
 
 pub fn initialize_mint<'info>(
@@ -310,28 +306,175 @@ pub fn set_authority<'info>(
         spl_new_authority = new_authority.as_ref()
     }
 
+    let signers = multisig_signers(&ctx.remaining_accounts);
     let ix = spl_token::instruction::set_authority(
         &spl_token::ID,
         ctx.accounts.account_or_mint.key,
         spl_new_authority,
         authority_type,
         ctx.accounts.current_authority.key,
-        &[], // TODO: Support multisig signers.
+        &signers,
+    )?;
+    let mut account_infos = vec![ctx.accounts.account_or_mint, ctx.accounts.current_authority];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn sync_native<'info>(ctx: CpiContext<'_, '_, '_, 'info, SyncNative<'info>>) -> Result<()> {
+    let ix = spl_token::instruction::sync_native(&spl_token::ID, ctx.accounts.account.key)?;
+    solana_program::program::invoke_signed(&ix, &[ctx.accounts.account], ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn initialize_multisig<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, InitializeMultisig<'info>>,
+    m: u8,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token::instruction::initialize_multisig(
+        &spl_token::ID,
+        ctx.accounts.multisig.key,
+        &signers,
+        m,
+    )?;
+    let mut account_infos = vec![ctx.accounts.multisig, ctx.accounts.rent];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn initialize_multisig2<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, InitializeMultisig2<'info>>,
+    m: u8,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token::instruction::initialize_multisig2(
+        &spl_token::ID,
+        ctx.accounts.multisig.key,
+        &signers,
+        m,
+    )?;
+    let mut account_infos = vec![ctx.accounts.multisig];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn mint_to_checked<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, MintToChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token::instruction::mint_to_checked(
+        &spl_token::ID,
+        ctx.accounts.mint.key,
+        ctx.accounts.to.key,
+        ctx.accounts.authority.key,
+        &signers,
+        amount,
+        decimals,
+    )?;
+    let mut account_infos = vec![ctx.accounts.mint, ctx.accounts.to, ctx.accounts.authority];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn burn_checked<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, BurnChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token::instruction::burn_checked(
+        &spl_token::ID,
+        ctx.accounts.from.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.authority.key,
+        &signers,
+        amount,
+        decimals,
+    )?;
+    let mut account_infos = vec![ctx.accounts.from, ctx.accounts.mint, ctx.accounts.authority];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn initialize_account2<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, InitializeAccount2<'info>>,
+) -> Result<()> {
+    let ix = spl_token::instruction::initialize_account2(
+        &spl_token::ID,
+        ctx.accounts.account.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.authority.key,
     )?;
     solana_program::program::invoke_signed(
         &ix,
-        &[ctx.accounts.account_or_mint, ctx.accounts.current_authority],
+        &[ctx.accounts.account, ctx.accounts.mint, ctx.accounts.rent],
         ctx.signer_seeds,
     )
     .map_err(Into::into)
 }
 
-pub fn sync_native<'info>(ctx: CpiContext<'_, '_, '_, 'info, SyncNative<'info>>) -> Result<()> {
-    let ix = spl_token::instruction::sync_native(&spl_token::ID, ctx.accounts.account.key)?;
+pub fn initialize_immutable_owner<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, InitializeImmutableOwner<'info>>,
+) -> Result<()> {
+    let ix = spl_token::instruction::initialize_immutable_owner(
+        &spl_token::ID,
+        ctx.accounts.account.key,
+    )?;
     solana_program::program::invoke_signed(&ix, &[ctx.accounts.account], ctx.signer_seeds)
         .map_err(Into::into)
 }
 
+/// Returns the size in bytes of a token account for the mint.
+pub fn get_account_data_size<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, GetAccountDataSize<'info>>,
+) -> Result<u64> {
+    let ix = spl_token::instruction::get_account_data_size(&spl_token::ID, ctx.accounts.mint.key)?;
+    let program_id = ix.program_id;
+    solana_program::program::invoke_signed(&ix, &[ctx.accounts.mint], ctx.signer_seeds)?;
+    let data = return_data(&program_id)?;
+    data.try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|_| ProgramError::InvalidInstructionData.into())
+}
+
+/// Converts a raw `amount` into its UI representation using the mint's decimals.
+pub fn amount_to_ui_amount<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, AmountToUiAmount<'info>>,
+    amount: u64,
+) -> Result<String> {
+    let ix =
+        spl_token::instruction::amount_to_ui_amount(&spl_token::ID, ctx.accounts.mint.key, amount)?;
+    let program_id = ix.program_id;
+    solana_program::program::invoke_signed(&ix, &[ctx.accounts.mint], ctx.signer_seeds)?;
+    let data = return_data(&program_id)?;
+    String::from_utf8(data).map_err(|_| ProgramError::InvalidInstructionData.into())
+}
+
+/// Converts a UI amount string into the raw amount using the mint's decimals.
+pub fn ui_amount_to_amount<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, UiAmountToAmount<'info>>,
+    ui_amount: &str,
+) -> Result<u64> {
+    let ix = spl_token::instruction::ui_amount_to_amount(
+        &spl_token::ID,
+        ctx.accounts.mint.key,
+        ui_amount,
+    )?;
+    let program_id = ix.program_id;
+    solana_program::program::invoke_signed(&ix, &[ctx.accounts.mint], ctx.signer_seeds)?;
+    let data = return_data(&program_id)?;
+    data.try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|_| ProgramError::InvalidInstructionData.into())
+}
+
 #[derive(Accounts)]
 pub struct Transfer<'info> {
     pub from: AccountInfo<'info>,
@@ -440,11 +583,78 @@ pub struct SyncNative<'info> {
     pub account: AccountInfo<'info>,
 }
 
+#[derive(Accounts)]
+pub struct InitializeMultisig<'info> {
+    pub multisig: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeMultisig2<'info> {
+    pub multisig: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MintToChecked<'info> {
+    pub mint: AccountInfo<'info>,
+    pub to: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BurnChecked<'info> {
+    pub mint: AccountInfo<'info>,
+    pub from: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeAccount2<'info> {
+    pub account: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeImmutableOwner<'info> {
+    pub account: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct GetAccountDataSize<'info> {
+    pub mint: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AmountToUiAmount<'info> {
+    pub mint: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UiAmountToAmount<'info> {
+    pub mint: AccountInfo<'info>,
+}
+
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct TokenAccount(spl_token::state::Account);
 
 impl TokenAccount {
     pub const LEN: usize = spl_token::state::Account::LEN;
+
+    pub fn new(state: spl_token::state::Account) -> Self {
+        Self(state)
+    }
+
+    pub fn into_inner(self) -> spl_token::state::Account {
+        self.0
+    }
+}
+
+impl From<spl_token::state::Account> for TokenAccount {
+    fn from(state: spl_token::state::Account) -> Self {
+        Self(state)
+    }
 }
 
 impl anchor_lang::AccountDeserialize for TokenAccount {
@@ -455,7 +665,15 @@ impl anchor_lang::AccountDeserialize for TokenAccount {
     }
 }
 
-impl anchor_lang::AccountSerialize for TokenAccount {}
+impl anchor_lang::AccountSerialize for TokenAccount {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
+        let mut data = [0u8; Self::LEN];
+        spl_token::state::Account::pack(self.0, &mut data)?;
+        writer
+            .write_all(&data)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+    }
+}
 
 impl anchor_lang::Owner for TokenAccount {
     fn owner() -> Pubkey {
@@ -479,6 +697,20 @@ pub struct Mint(spl_token::state::Mint);
 
 impl Mint {
     pub const LEN: usize = spl_token::state::Mint::LEN;
+
+    pub fn new(state: spl_token::state::Mint) -> Self {
+        Self(state)
+    }
+
+    pub fn into_inner(self) -> spl_token::state::Mint {
+        self.0
+    }
+}
+
+impl From<spl_token::state::Mint> for Mint {
+    fn from(state: spl_token::state::Mint) -> Self {
+        Self(state)
+    }
 }
 
 impl anchor_lang::AccountDeserialize for Mint {
@@ -489,7 +721,15 @@ impl anchor_lang::AccountDeserialize for Mint {
     }
 }
 
-impl anchor_lang::AccountSerialize for Mint {}
+impl anchor_lang::AccountSerialize for Mint {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
+        let mut data = [0u8; Self::LEN];
+        spl_token::state::Mint::pack(self.0, &mut data)?;
+        writer
+            .write_all(&data)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+    }
+}
 
 impl anchor_lang::Owner for Mint {
     fn owner() -> Pubkey {
@@ -508,6 +748,62 @@ impl Deref for Mint {
 #[cfg(feature = "idl-build")]
 impl anchor_lang::IdlBuild for Mint {}
 
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Multisig(spl_token::state::Multisig);
+
+impl Multisig {
+    pub const LEN: usize = spl_token::state::Multisig::LEN;
+
+    pub fn new(state: spl_token::state::Multisig) -> Self {
+        Self(state)
+    }
+
+    pub fn into_inner(self) -> spl_token::state::Multisig {
+        self.0
+    }
+}
+
+impl From<spl_token::state::Multisig> for Multisig {
+    fn from(state: spl_token::state::Multisig) -> Self {
+        Self(state)
+    }
+}
+
+impl anchor_lang::AccountDeserialize for Multisig {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        spl_token::state::Multisig::unpack(buf)
+            .map(Multisig)
+            .map_err(Into::into)
+    }
+}
+
+impl anchor_lang::AccountSerialize for Multisig {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
+        let mut data = [0u8; Self::LEN];
+        spl_token::state::Multisig::pack(self.0, &mut data)?;
+        writer
+            .write_all(&data)
+            .map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+    }
+}
+
+impl anchor_lang::Owner for Multisig {
+    fn owner() -> Pubkey {
+        ID
+    }
+}
+
+impl Deref for Multisig {
+    type Target = spl_token::state::Multisig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::IdlBuild for Multisig {}
+
 #[derive(Clone)]
 pub struct Token;
 
@@ -517,29 +813,748 @@ impl anchor_lang::Id for Token {
     }
 }
 
-// Field parsers to save compute. All account validation is assumed to be done
-// outside of these methods.
+// Field parsers to save compute. Each read checks that the account is owned by
+// the legacy Token program or, with the `token_2022` feature, Token-2022, that
+// its data has the expected length and account type, and that it is
+// initialized, then reads the field in place without unpacking the whole
+// state. Failures map to anchor errors:
+// `AccountOwnedByWrongProgram`, `AccountDidNotDeserialize` and
+// `AccountNotInitialized`.
 pub mod accessor {
     use super::*;
+    use spl_token::state::AccountState;
+
+    /// Token-2022 program id. Token-2022 accounts share the legacy base layout,
+    /// so with the `token_2022` feature these accessors accept them too.
+    #[cfg(feature = "token_2022")]
+    pub use spl_token_2022::ID as TOKEN_2022_ID;
+
+    const ACCOUNT_LEN: usize = spl_token::state::Account::LEN;
+    const MINT_LEN: usize = spl_token::state::Mint::LEN;
+    const MULTISIG_LEN: usize = spl_token::state::Multisig::LEN;
+
+    // Token-2022 stores the account type right after the (padded) base state.
+    const ACCOUNT_TYPE_OFFSET: usize = ACCOUNT_LEN;
+    const ACCOUNT_TYPE_MINT: u8 = 1;
+    const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
 
     pub fn amount(account: &AccountInfo) -> Result<u64> {
-        let bytes = account.try_borrow_data()?;
-        let mut amount_bytes = [0u8; 8];
-        amount_bytes.copy_from_slice(&bytes[64..72]);
-        Ok(u64::from_le_bytes(amount_bytes))
+        let bytes = token_account_data(account)?;
+        Ok(read_u64(&bytes, 64))
     }
 
     pub fn mint(account: &AccountInfo) -> Result<Pubkey> {
-        let bytes = account.try_borrow_data()?;
-        let mut mint_bytes = [0u8; 32];
-        mint_bytes.copy_from_slice(&bytes[..32]);
-        Ok(Pubkey::new_from_array(mint_bytes))
+        let bytes = token_account_data(account)?;
+        Ok(read_pubkey(&bytes, 0))
     }
 
     pub fn authority(account: &AccountInfo) -> Result<Pubkey> {
+        let bytes = token_account_data(account)?;
+        Ok(read_pubkey(&bytes, 32))
+    }
+
+    pub fn delegate(account: &AccountInfo) -> Result<Option<Pubkey>> {
+        let bytes = token_account_data(account)?;
+        read_coption_pubkey(&bytes, 72)
+    }
+
+    pub fn state(account: &AccountInfo) -> Result<AccountState> {
+        let bytes = token_account_data(account)?;
+        read_state(&bytes)
+    }
+
+    pub fn is_native(account: &AccountInfo) -> Result<Option<u64>> {
+        let bytes = token_account_data(account)?;
+        match read_coption_tag(&bytes, 109)? {
+            true => Ok(Some(read_u64(&bytes, 113))),
+            false => Ok(None),
+        }
+    }
+
+    pub fn delegated_amount(account: &AccountInfo) -> Result<u64> {
+        let bytes = token_account_data(account)?;
+        Ok(read_u64(&bytes, 121))
+    }
+
+    pub fn close_authority(account: &AccountInfo) -> Result<Option<Pubkey>> {
+        let bytes = token_account_data(account)?;
+        read_coption_pubkey(&bytes, 129)
+    }
+
+    pub fn supply(mint: &AccountInfo) -> Result<u64> {
+        let bytes = mint_data(mint)?;
+        Ok(read_u64(&bytes, 36))
+    }
+
+    pub fn decimals(mint: &AccountInfo) -> Result<u8> {
+        let bytes = mint_data(mint)?;
+        Ok(bytes[44])
+    }
+
+    pub fn mint_authority(mint: &AccountInfo) -> Result<Option<Pubkey>> {
+        let bytes = mint_data(mint)?;
+        read_coption_pubkey(&bytes, 0)
+    }
+
+    pub fn freeze_authority(mint: &AccountInfo) -> Result<Option<Pubkey>> {
+        let bytes = mint_data(mint)?;
+        read_coption_pubkey(&bytes, 46)
+    }
+
+    pub(crate) fn check_owner(account: &AccountInfo) -> Result<()> {
+        if *account.owner != ID && !is_token_2022(account.owner) {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "token_2022")]
+    fn is_token_2022(owner: &Pubkey) -> bool {
+        *owner == TOKEN_2022_ID
+    }
+
+    #[cfg(not(feature = "token_2022"))]
+    fn is_token_2022(_owner: &Pubkey) -> bool {
+        false
+    }
+
+    /// Checks the length and account type of token account data.
+    pub(crate) fn check_token_account_layout(bytes: &[u8]) -> Result<()> {
+        let valid = bytes.len() == ACCOUNT_LEN
+            || (bytes.len() > ACCOUNT_LEN
+                && bytes.len() != MULTISIG_LEN
+                && bytes[ACCOUNT_TYPE_OFFSET] == ACCOUNT_TYPE_ACCOUNT);
+        if !valid {
+            return Err(ErrorCode::AccountDidNotDeserialize.into());
+        }
+        for offset in [72, 109, 129] {
+            read_coption_tag(bytes, offset)?;
+        }
+        read_state(bytes).map(|_| ())
+    }
+
+    /// Checks the length and account type of mint data.
+    pub(crate) fn check_mint_layout(bytes: &[u8]) -> Result<()> {
+        let valid = bytes.len() == MINT_LEN
+            || (bytes.len() > ACCOUNT_LEN
+                && bytes.len() != MULTISIG_LEN
+                && bytes[ACCOUNT_TYPE_OFFSET] == ACCOUNT_TYPE_MINT);
+        if !valid {
+            return Err(ErrorCode::AccountDidNotDeserialize.into());
+        }
+        for offset in [0, 46] {
+            read_coption_tag(bytes, offset)?;
+        }
+        match bytes[45] {
+            0 => Err(ErrorCode::AccountNotInitialized.into()),
+            1 => Ok(()),
+            _ => Err(ErrorCode::AccountDidNotDeserialize.into()),
+        }
+    }
+
+    fn token_account_data<'a>(account: &'a AccountInfo) -> Result<Ref<'a, &'a mut [u8]>> {
+        check_owner(account)?;
         let bytes = account.try_borrow_data()?;
-        let mut owner_bytes = [0u8; 32];
-        owner_bytes.copy_from_slice(&bytes[32..64]);
-        Ok(Pubkey::new_from_array(owner_bytes))
+        check_token_account_layout(&bytes)?;
+        Ok(bytes)
+    }
+
+    fn mint_data<'a>(account: &'a AccountInfo) -> Result<Ref<'a, &'a mut [u8]>> {
+        check_owner(account)?;
+        let bytes = account.try_borrow_data()?;
+        check_mint_layout(&bytes)?;
+        Ok(bytes)
+    }
+
+    fn read_state(bytes: &[u8]) -> Result<AccountState> {
+        match bytes[108] {
+            0 => Err(ErrorCode::AccountNotInitialized.into()),
+            1 => Ok(AccountState::Initialized),
+            2 => Ok(AccountState::Frozen),
+            _ => Err(ErrorCode::AccountDidNotDeserialize.into()),
+        }
+    }
+
+    pub(crate) fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+        let mut value = [0u8; 8];
+        value.copy_from_slice(&bytes[offset..offset + 8]);
+        u64::from_le_bytes(value)
+    }
+
+    pub(crate) fn read_pubkey(bytes: &[u8], offset: usize) -> Pubkey {
+        let mut key = [0u8; 32];
+        key.copy_from_slice(&bytes[offset..offset + 32]);
+        Pubkey::new_from_array(key)
+    }
+
+    pub(crate) fn read_coption_tag(bytes: &[u8], offset: usize) -> Result<bool> {
+        match bytes[offset..offset + 4] {
+            [0, 0, 0, 0] => Ok(false),
+            [1, 0, 0, 0] => Ok(true),
+            _ => Err(ErrorCode::AccountDidNotDeserialize.into()),
+        }
+    }
+
+    pub(crate) fn read_coption_pubkey(bytes: &[u8], offset: usize) -> Result<Option<Pubkey>> {
+        match read_coption_tag(bytes, offset)? {
+            true => Ok(Some(read_pubkey(bytes, offset + 4))),
+            false => Ok(None),
+        }
+    }
+}
+
+/// Borrowed view of a token account that reads fields in place.
+///
+/// The data is validated once when the view is created, so each getter is a
+/// plain read at a fixed offset instead of a full `unpack`. Works for legacy
+/// and Token-2022 accounts, which share the same base layout.
+///
+/// Only the token program may change a token account, so the setters are only
+/// available on views created with [`TokenAccountView::new`] over a raw
+/// `&mut [u8]`, e.g. to build fixtures in tests.
+///
+/// ```rust,ignore
+/// let view = TokenAccountView::load(&account_info)?;
+/// let amount = view.amount();
+/// ```
+pub struct TokenAccountView<D> {
+    data: D,
+}
+
+impl<'a> TokenAccountView<Ref<'a, [u8]>> {
+    pub fn load(account: &'a AccountInfo) -> Result<Self> {
+        accessor::check_owner(account)?;
+        let data = Ref::map(account.try_borrow_data()?, |data| &**data);
+        Self::new(data)
+    }
+}
+
+impl<D: Deref<Target = [u8]>> TokenAccountView<D> {
+    /// Creates a view over raw token account data. The owner is not checked.
+    pub fn new(data: D) -> Result<Self> {
+        accessor::check_token_account_layout(&data)?;
+        Ok(Self { data })
+    }
+
+    pub fn mint(&self) -> Pubkey {
+        accessor::read_pubkey(&self.data, 0)
+    }
+
+    pub fn owner(&self) -> Pubkey {
+        accessor::read_pubkey(&self.data, 32)
+    }
+
+    pub fn amount(&self) -> u64 {
+        accessor::read_u64(&self.data, 64)
+    }
+
+    pub fn delegate(&self) -> Option<Pubkey> {
+        read_validated_coption_pubkey(&self.data, 72)
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.data[108] == spl_token::state::AccountState::Frozen as u8
+    }
+
+    pub fn is_native(&self) -> Option<u64> {
+        match self.data[109] {
+            1 => Some(accessor::read_u64(&self.data, 113)),
+            _ => None,
+        }
+    }
+
+    pub fn delegated_amount(&self) -> u64 {
+        accessor::read_u64(&self.data, 121)
+    }
+
+    pub fn close_authority(&self) -> Option<Pubkey> {
+        read_validated_coption_pubkey(&self.data, 129)
+    }
+}
+
+impl<D: DerefMut<Target = [u8]>> TokenAccountView<D> {
+    pub fn set_owner(&mut self, owner: &Pubkey) {
+        write_pubkey(&mut self.data, 32, owner);
+    }
+
+    pub fn set_amount(&mut self, amount: u64) {
+        self.data[64..72].copy_from_slice(&amount.to_le_bytes());
+    }
+
+    pub fn set_delegate(&mut self, delegate: Option<&Pubkey>) {
+        write_coption_pubkey(&mut self.data, 72, delegate);
+    }
+
+    pub fn set_frozen(&mut self, frozen: bool) {
+        self.data[108] = match frozen {
+            true => spl_token::state::AccountState::Frozen as u8,
+            false => spl_token::state::AccountState::Initialized as u8,
+        };
+    }
+
+    pub fn set_delegated_amount(&mut self, delegated_amount: u64) {
+        self.data[121..129].copy_from_slice(&delegated_amount.to_le_bytes());
+    }
+
+    pub fn set_close_authority(&mut self, close_authority: Option<&Pubkey>) {
+        write_coption_pubkey(&mut self.data, 129, close_authority);
+    }
+}
+
+/// Borrowed view of a mint that reads fields in place.
+///
+/// See [`TokenAccountView`]; the same rules apply to legacy and Token-2022 mints,
+/// and the setters are likewise only meant for raw `&mut [u8]` data.
+pub struct MintView<D> {
+    data: D,
+}
+
+impl<'a> MintView<Ref<'a, [u8]>> {
+    pub fn load(account: &'a AccountInfo) -> Result<Self> {
+        accessor::check_owner(account)?;
+        let data = Ref::map(account.try_borrow_data()?, |data| &**data);
+        Self::new(data)
+    }
+}
+
+impl<D: Deref<Target = [u8]>> MintView<D> {
+    /// Creates a view over raw mint data. The owner is not checked.
+    pub fn new(data: D) -> Result<Self> {
+        accessor::check_mint_layout(&data)?;
+        Ok(Self { data })
+    }
+
+    pub fn mint_authority(&self) -> Option<Pubkey> {
+        read_validated_coption_pubkey(&self.data, 0)
+    }
+
+    pub fn supply(&self) -> u64 {
+        accessor::read_u64(&self.data, 36)
+    }
+
+    pub fn decimals(&self) -> u8 {
+        self.data[44]
+    }
+
+    pub fn freeze_authority(&self) -> Option<Pubkey> {
+        read_validated_coption_pubkey(&self.data, 46)
+    }
+}
+
+impl<D: DerefMut<Target = [u8]>> MintView<D> {
+    pub fn set_mint_authority(&mut self, mint_authority: Option<&Pubkey>) {
+        write_coption_pubkey(&mut self.data, 0, mint_authority);
+    }
+
+    pub fn set_supply(&mut self, supply: u64) {
+        self.data[36..44].copy_from_slice(&supply.to_le_bytes());
+    }
+
+    pub fn set_freeze_authority(&mut self, freeze_authority: Option<&Pubkey>) {
+        write_coption_pubkey(&mut self.data, 46, freeze_authority);
+    }
+}
+
+// The `COption` tags are checked when a view is created, so any tag other than
+// `Some` can only be `None` here.
+fn read_validated_coption_pubkey(bytes: &[u8], offset: usize) -> Option<Pubkey> {
+    match bytes[offset] {
+        1 => Some(accessor::read_pubkey(bytes, offset + 4)),
+        _ => None,
+    }
+}
+
+fn write_pubkey(bytes: &mut [u8], offset: usize, key: &Pubkey) {
+    bytes[offset..offset + 32].copy_from_slice(key.as_ref());
+}
+
+fn write_coption_pubkey(bytes: &mut [u8], offset: usize, key: Option<&Pubkey>) {
+    match key {
+        Some(key) => {
+            bytes[offset..offset + 4].copy_from_slice(&[1, 0, 0, 0]);
+            write_pubkey(bytes, offset + 4, key);
+        }
+        None => bytes[offset..offset + 36].fill(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program_option::COption;
+    use anchor_lang::{AccountDeserialize, AccountSerialize};
+
+    fn round_trip<T: AccountSerialize + AccountDeserialize>(account: &T) -> (Vec<u8>, T) {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        let back = T::try_deserialize(&mut data.as_slice()).unwrap();
+        (data, back)
+    }
+
+    #[test]
+    fn token_account_round_trips() {
+        let account = TokenAccount::new(spl_token::state::Account {
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            amount: 42,
+            delegate: COption::Some(Pubkey::new_unique()),
+            state: spl_token::state::AccountState::Frozen,
+            delegated_amount: 7,
+            ..Default::default()
+        });
+        let (data, back) = round_trip(&account);
+        assert_eq!(data.len(), TokenAccount::LEN);
+        assert_eq!(back, account);
+    }
+
+    #[test]
+    fn mint_round_trips() {
+        let mint = Mint::new(spl_token::state::Mint {
+            mint_authority: COption::Some(Pubkey::new_unique()),
+            supply: 1_000,
+            decimals: 9,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        });
+        let (data, back) = round_trip(&mint);
+        assert_eq!(data.len(), Mint::LEN);
+        assert_eq!(back, mint);
+    }
+
+    #[test]
+    fn multisig_round_trips() {
+        let mut signers = [Pubkey::default(); spl_token::instruction::MAX_SIGNERS];
+        signers[0] = Pubkey::new_unique();
+        signers[1] = Pubkey::new_unique();
+        let multisig = Multisig::new(spl_token::state::Multisig {
+            m: 1,
+            n: 2,
+            is_initialized: true,
+            signers,
+        });
+        let (data, back) = round_trip(&multisig);
+        assert_eq!(data.len(), Multisig::LEN);
+        assert_eq!(back, multisig);
+    }
+
+    fn account_state() -> spl_token::state::Account {
+        spl_token::state::Account {
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            amount: 42,
+            delegate: COption::Some(Pubkey::new_unique()),
+            state: spl_token::state::AccountState::Initialized,
+            is_native: COption::Some(2_039_280),
+            delegated_amount: 7,
+            close_authority: COption::Some(Pubkey::new_unique()),
+        }
+    }
+
+    fn mint_state() -> spl_token::state::Mint {
+        spl_token::state::Mint {
+            mint_authority: COption::Some(Pubkey::new_unique()),
+            supply: 1_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+    }
+
+    fn packed<T: Pack>(state: T) -> Vec<u8> {
+        let mut data = vec![0u8; T::LEN];
+        state.pack_into_slice(&mut data);
+        data
+    }
+
+    fn account_info<'a>(
+        key: &'a Pubkey,
+        owner: &'a Pubkey,
+        lamports: &'a mut u64,
+        data: &'a mut [u8],
+    ) -> AccountInfo<'a> {
+        AccountInfo::new(key, false, false, lamports, data, owner, false, 0)
+    }
+
+    #[test]
+    fn accessors_read_token_account() {
+        let state = account_state();
+        let mut data = packed(state);
+        let (key, mut lamports) = (Pubkey::new_unique(), 0);
+        let info = account_info(&key, &ID, &mut lamports, &mut data);
+
+        assert_eq!(accessor::mint(&info).unwrap(), state.mint);
+        assert_eq!(accessor::authority(&info).unwrap(), state.owner);
+        assert_eq!(accessor::amount(&info).unwrap(), 42);
+        assert_eq!(accessor::delegate(&info).unwrap(), state.delegate.into());
+        assert_eq!(accessor::state(&info).unwrap(), state.state);
+        assert_eq!(accessor::is_native(&info).unwrap(), Some(2_039_280));
+        assert_eq!(accessor::delegated_amount(&info).unwrap(), 7);
+        assert_eq!(
+            accessor::close_authority(&info).unwrap(),
+            state.close_authority.into()
+        );
+    }
+
+    #[test]
+    fn accessors_read_mint() {
+        let state = mint_state();
+        let mut data = packed(state);
+        let (key, mut lamports) = (Pubkey::new_unique(), 0);
+        let info = account_info(&key, &ID, &mut lamports, &mut data);
+
+        assert_eq!(accessor::supply(&info).unwrap(), 1_000);
+        assert_eq!(accessor::decimals(&info).unwrap(), 6);
+        assert_eq!(
+            accessor::mint_authority(&info).unwrap(),
+            state.mint_authority.into()
+        );
+        assert_eq!(accessor::freeze_authority(&info).unwrap(), None);
+    }
+
+    #[test]
+    fn accessors_reject_short_buffers() {
+        let (key, mut lamports) = (Pubkey::new_unique(), 0);
+        let mut data = packed(account_state());
+        let info = account_info(&key, &ID, &mut lamports, &mut data[..TokenAccount::LEN - 1]);
+        assert_eq!(
+            accessor::amount(&info).unwrap_err(),
+            ErrorCode::AccountDidNotDeserialize.into()
+        );
+
+        let mut data = packed(mint_state());
+        let info = account_info(&key, &ID, &mut lamports, &mut data[..Mint::LEN - 1]);
+        assert_eq!(
+            accessor::supply(&info).unwrap_err(),
+            ErrorCode::AccountDidNotDeserialize.into()
+        );
+    }
+
+    #[test]
+    fn accessors_reject_other_layouts() {
+        let (key, mut lamports) = (Pubkey::new_unique(), 0);
+        let mut data = packed(mint_state());
+        let info = account_info(&key, &ID, &mut lamports, &mut data);
+        assert_eq!(
+            accessor::amount(&info).unwrap_err(),
+            ErrorCode::AccountDidNotDeserialize.into()
+        );
+
+        let mut data = vec![0u8; Multisig::LEN];
+        let info = account_info(&key, &ID, &mut lamports, &mut data);
+        assert_eq!(
+            accessor::supply(&info).unwrap_err(),
+            ErrorCode::AccountDidNotDeserialize.into()
+        );
+    }
+
+    #[test]
+    fn accessors_reject_wrong_owner() {
+        let (key, owner, mut lamports) = (Pubkey::new_unique(), Pubkey::new_unique(), 0);
+        let mut data = packed(account_state());
+        let info = account_info(&key, &owner, &mut lamports, &mut data);
+        assert_eq!(
+            accessor::amount(&info).unwrap_err(),
+            ErrorCode::AccountOwnedByWrongProgram.into()
+        );
+
+        let mut data = packed(mint_state());
+        let info = account_info(&key, &owner, &mut lamports, &mut data);
+        assert_eq!(
+            accessor::decimals(&info).unwrap_err(),
+            ErrorCode::AccountOwnedByWrongProgram.into()
+        );
+    }
+
+    #[test]
+    fn views_read_token_account() {
+        let state = account_state();
+        let mut data = packed(state);
+        let (key, mut lamports) = (Pubkey::new_unique(), 0);
+        let info = account_info(&key, &ID, &mut lamports, &mut data);
+
+        let view = TokenAccountView::load(&info).unwrap();
+        assert_eq!(view.mint(), state.mint);
+        assert_eq!(view.owner(), state.owner);
+        assert_eq!(view.amount(), state.amount);
+        assert_eq!(view.delegate(), state.delegate.into());
+        assert!(!view.is_frozen());
+        assert_eq!(view.is_native(), state.is_native.into());
+        assert_eq!(view.delegated_amount(), state.delegated_amount);
+        assert_eq!(view.close_authority(), state.close_authority.into());
+    }
+
+    #[test]
+    fn views_read_mint() {
+        let state = mint_state();
+        let mut data = packed(state);
+        let (key, mut lamports) = (Pubkey::new_unique(), 0);
+        let info = account_info(&key, &ID, &mut lamports, &mut data);
+
+        let view = MintView::load(&info).unwrap();
+        assert_eq!(view.mint_authority(), state.mint_authority.into());
+        assert_eq!(view.supply(), state.supply);
+        assert_eq!(view.decimals(), state.decimals);
+        assert_eq!(view.freeze_authority(), None);
+    }
+
+    #[test]
+    fn views_reject_wrong_owner() {
+        let (key, owner, mut lamports) = (Pubkey::new_unique(), Pubkey::new_unique(), 0);
+        let mut data = packed(account_state());
+        let info = account_info(&key, &owner, &mut lamports, &mut data);
+        assert_eq!(
+            TokenAccountView::load(&info).err(),
+            Some(ErrorCode::AccountOwnedByWrongProgram.into())
+        );
+
+        let mut data = packed(mint_state());
+        let info = account_info(&key, &owner, &mut lamports, &mut data);
+        assert_eq!(
+            MintView::load(&info).err(),
+            Some(ErrorCode::AccountOwnedByWrongProgram.into())
+        );
+    }
+
+    #[test]
+    fn views_reject_invalid_data() {
+        let invalid = Some(ErrorCode::AccountDidNotDeserialize.into());
+        let account = packed(account_state());
+        let mint = packed(mint_state());
+
+        assert_eq!(
+            TokenAccountView::new(&account[..TokenAccount::LEN - 1]).err(),
+            invalid
+        );
+        assert_eq!(MintView::new(&mint[..Mint::LEN - 1]).err(), invalid);
+        assert_eq!(TokenAccountView::new(&mint[..]).err(), invalid);
+        assert_eq!(MintView::new(&account[..]).err(), invalid);
+
+        let mut bad_delegate = account.clone();
+        bad_delegate[72] = 2;
+        assert_eq!(TokenAccountView::new(&bad_delegate[..]).err(), invalid);
+
+        let mut bad_state = account.clone();
+        bad_state[108] = 3;
+        assert_eq!(TokenAccountView::new(&bad_state[..]).err(), invalid);
+
+        let mut bad_mint_authority = mint.clone();
+        bad_mint_authority[0] = 2;
+        assert_eq!(MintView::new(&bad_mint_authority[..]).err(), invalid);
+    }
+
+    #[test]
+    fn view_setters_round_trip() {
+        let mut data = packed(account_state());
+        let (owner, delegate) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut view = TokenAccountView::new(&mut data[..]).unwrap();
+        view.set_owner(&owner);
+        view.set_amount(1_000);
+        view.set_delegate(Some(&delegate));
+        view.set_frozen(true);
+        view.set_delegated_amount(500);
+        view.set_close_authority(None);
+
+        let account = spl_token::state::Account::unpack(&data).unwrap();
+        assert_eq!(account.owner, owner);
+        assert_eq!(account.amount, 1_000);
+        assert_eq!(account.delegate, COption::Some(delegate));
+        assert_eq!(account.state, spl_token::state::AccountState::Frozen);
+        assert_eq!(account.delegated_amount, 500);
+        assert_eq!(account.close_authority, COption::None);
+
+        let mut data = packed(mint_state());
+        let freeze_authority = Pubkey::new_unique();
+        let mut view = MintView::new(&mut data[..]).unwrap();
+        view.set_mint_authority(None);
+        view.set_supply(5_000);
+        view.set_freeze_authority(Some(&freeze_authority));
+
+        let mint = spl_token::state::Mint::unpack(&data).unwrap();
+        assert_eq!(mint.mint_authority, COption::None);
+        assert_eq!(mint.supply, 5_000);
+        assert_eq!(mint.freeze_authority, COption::Some(freeze_authority));
+    }
+
+    #[cfg(feature = "token_2022")]
+    #[test]
+    fn accessors_read_token_2022_layout() {
+        use spl_token_2022::extension::immutable_owner::ImmutableOwner;
+        use spl_token_2022::extension::mint_close_authority::MintCloseAuthority;
+        use spl_token_2022::extension::{ExtensionType, StateWithExtensionsMut};
+
+        let (key, mut lamports) = (Pubkey::new_unique(), 0);
+
+        let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&[
+            ExtensionType::ImmutableOwner,
+        ])
+        .unwrap();
+        let mut account_data = vec![0u8; len];
+        let mut state =
+            StateWithExtensionsMut::<spl_token_2022::state::Account>::unpack_uninitialized(
+                &mut account_data,
+            )
+            .unwrap();
+        state.init_extension::<ImmutableOwner>(true).unwrap();
+        state.base = spl_token_2022::state::Account {
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            amount: 42,
+            state: spl_token_2022::state::AccountState::Frozen,
+            ..Default::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        let base = state.base;
+
+        let info = account_info(
+            &key,
+            &accessor::TOKEN_2022_ID,
+            &mut lamports,
+            &mut account_data,
+        );
+        assert_eq!(accessor::mint(&info).unwrap(), base.mint);
+        assert_eq!(accessor::amount(&info).unwrap(), 42);
+        assert_eq!(
+            accessor::state(&info).unwrap(),
+            spl_token::state::AccountState::Frozen
+        );
+        assert_eq!(
+            accessor::supply(&info).unwrap_err(),
+            ErrorCode::AccountDidNotDeserialize.into()
+        );
+
+        let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+            ExtensionType::MintCloseAuthority,
+        ])
+        .unwrap();
+        let mut mint_data = vec![0u8; len];
+        let mut state =
+            StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(
+                &mut mint_data,
+            )
+            .unwrap();
+        state.init_extension::<MintCloseAuthority>(true).unwrap();
+        state.base = spl_token_2022::state::Mint {
+            supply: 1_000,
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+
+        let mut lamports = 0;
+        let info = account_info(
+            &key,
+            &accessor::TOKEN_2022_ID,
+            &mut lamports,
+            &mut mint_data,
+        );
+        assert_eq!(accessor::supply(&info).unwrap(), 1_000);
+        assert_eq!(accessor::decimals(&info).unwrap(), 6);
+        assert_eq!(
+            accessor::amount(&info).unwrap_err(),
+            ErrorCode::AccountDidNotDeserialize.into()
+        );
     }
 }
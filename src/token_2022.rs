@@ -0,0 +1,707 @@
+use anchor_lang::solana_program::account_info::AccountInfo;
+
+use anchor_lang::solana_program::program_error::ProgramError;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_lang::{context::CpiContext, Accounts};
+use anchor_lang::{solana_program, Result};
+
+pub use spl_token_2022;
+pub use spl_token_2022::ID;
+
+use crate::common::{multisig_signers, return_data};
+
+// Every wrapper below builds its instruction against `ctx.program`, so the
+// same call works for both the legacy Token program and Token-2022. The
+// `spl_token_2022` instruction builders reject any other program id with
+// `ProgramError::IncorrectProgramId` before anything is invoked.
+
+pub fn transfer<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, Transfer<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    #[allow(deprecated)]
+    let ix = spl_token_2022::instruction::transfer(
+        ctx.program.key,
+        ctx.accounts.from.key,
+        ctx.accounts.to.key,
+        ctx.accounts.authority.key,
+        &signers,
+        amount,
+    )?;
+    let mut account_infos = vec![ctx.accounts.from, ctx.accounts.to, ctx.accounts.authority];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn transfer_checked<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token_2022::instruction::transfer_checked(
+        ctx.program.key,
+        ctx.accounts.from.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.to.key,
+        ctx.accounts.authority.key,
+        &signers,
+        amount,
+        decimals,
+    )?;
+    let mut account_infos = vec![
+        ctx.accounts.from,
+        ctx.accounts.mint,
+        ctx.accounts.to,
+        ctx.accounts.authority,
+    ];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn mint_to<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, MintTo<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token_2022::instruction::mint_to(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.to.key,
+        ctx.accounts.authority.key,
+        &signers,
+        amount,
+    )?;
+    let mut account_infos = vec![ctx.accounts.to, ctx.accounts.mint, ctx.accounts.authority];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn burn<'info>(ctx: CpiContext<'_, '_, '_, 'info, Burn<'info>>, amount: u64) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token_2022::instruction::burn(
+        ctx.program.key,
+        ctx.accounts.from.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.authority.key,
+        &signers,
+        amount,
+    )?;
+    let mut account_infos = vec![ctx.accounts.from, ctx.accounts.mint, ctx.accounts.authority];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn approve<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, Approve<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token_2022::instruction::approve(
+        ctx.program.key,
+        ctx.accounts.to.key,
+        ctx.accounts.delegate.key,
+        ctx.accounts.authority.key,
+        &signers,
+        amount,
+    )?;
+    let mut account_infos = vec![
+        ctx.accounts.to,
+        ctx.accounts.delegate,
+        ctx.accounts.authority,
+    ];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn approve_checked<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, ApproveChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token_2022::instruction::approve_checked(
+        ctx.program.key,
+        ctx.accounts.to.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.delegate.key,
+        ctx.accounts.authority.key,
+        &signers,
+        amount,
+        decimals,
+    )?;
+    let mut account_infos = vec![
+        ctx.accounts.to,
+        ctx.accounts.mint,
+        ctx.accounts.delegate,
+        ctx.accounts.authority,
+    ];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn revoke<'info>(ctx: CpiContext<'_, '_, '_, 'info, Revoke<'info>>) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token_2022::instruction::revoke(
+        ctx.program.key,
+        ctx.accounts.source.key,
+        ctx.accounts.authority.key,
+        &signers,
+    )?;
+    let mut account_infos = vec![ctx.accounts.source, ctx.accounts.authority];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn initialize_account<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, InitializeAccount<'info>>,
+) -> Result<()> {
+    let ix = spl_token_2022::instruction::initialize_account(
+        ctx.program.key,
+        ctx.accounts.account.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.authority.key,
+    )?;
+    solana_program::program::invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.account,
+            ctx.accounts.mint,
+            ctx.accounts.authority,
+            ctx.accounts.rent,
+        ],
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+pub fn initialize_account3<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, InitializeAccount3<'info>>,
+) -> Result<()> {
+    let ix = spl_token_2022::instruction::initialize_account3(
+        ctx.program.key,
+        ctx.accounts.account.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.authority.key,
+    )?;
+    solana_program::program::invoke_signed(
+        &ix,
+        &[ctx.accounts.account, ctx.accounts.mint],
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+pub fn close_account<'info>(ctx: CpiContext<'_, '_, '_, 'info, CloseAccount<'info>>) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token_2022::instruction::close_account(
+        ctx.program.key,
+        ctx.accounts.account.key,
+        ctx.accounts.destination.key,
+        ctx.accounts.authority.key,
+        &signers,
+    )?;
+    let mut account_infos = vec![
+        ctx.accounts.account,
+        ctx.accounts.destination,
+        ctx.accounts.authority,
+    ];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn freeze_account<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, FreezeAccount<'info>>,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token_2022::instruction::freeze_account(
+        ctx.program.key,
+        ctx.accounts.account.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.authority.key,
+        &signers,
+    )?;
+    let mut account_infos = vec![
+        ctx.accounts.account,
+        ctx.accounts.mint,
+        ctx.accounts.authority,
+    ];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn thaw_account<'info>(ctx: CpiContext<'_, '_, '_, 'info, ThawAccount<'info>>) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token_2022::instruction::thaw_account(
+        ctx.program.key,
+        ctx.accounts.account.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.authority.key,
+        &signers,
+    )?;
+    let mut account_infos = vec![
+        ctx.accounts.account,
+        ctx.accounts.mint,
+        ctx.accounts.authority,
+    ];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn initialize_mint<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, InitializeMint<'info>>,
+    decimals: u8,
+    authority: &Pubkey,
+    freeze_authority: Option<&Pubkey>,
+) -> Result<()> {
+    let ix = spl_token_2022::instruction::initialize_mint(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        authority,
+        freeze_authority,
+        decimals,
+    )?;
+    solana_program::program::invoke_signed(
+        &ix,
+        &[ctx.accounts.mint, ctx.accounts.rent],
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+pub fn initialize_mint2<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, InitializeMint2<'info>>,
+    decimals: u8,
+    authority: &Pubkey,
+    freeze_authority: Option<&Pubkey>,
+) -> Result<()> {
+    let ix = spl_token_2022::instruction::initialize_mint2(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        authority,
+        freeze_authority,
+        decimals,
+    )?;
+    solana_program::program::invoke_signed(&ix, &[ctx.accounts.mint], ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn set_authority<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, SetAuthority<'info>>,
+    authority_type: spl_token_2022::instruction::AuthorityType,
+    new_authority: Option<Pubkey>,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token_2022::instruction::set_authority(
+        ctx.program.key,
+        ctx.accounts.account_or_mint.key,
+        new_authority.as_ref(),
+        authority_type,
+        ctx.accounts.current_authority.key,
+        &signers,
+    )?;
+    let mut account_infos = vec![ctx.accounts.account_or_mint, ctx.accounts.current_authority];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn sync_native<'info>(ctx: CpiContext<'_, '_, '_, 'info, SyncNative<'info>>) -> Result<()> {
+    let ix = spl_token_2022::instruction::sync_native(ctx.program.key, ctx.accounts.account.key)?;
+    solana_program::program::invoke_signed(&ix, &[ctx.accounts.account], ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn initialize_multisig<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, InitializeMultisig<'info>>,
+    m: u8,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token_2022::instruction::initialize_multisig(
+        ctx.program.key,
+        ctx.accounts.multisig.key,
+        &signers,
+        m,
+    )?;
+    let mut account_infos = vec![ctx.accounts.multisig, ctx.accounts.rent];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn initialize_multisig2<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, InitializeMultisig2<'info>>,
+    m: u8,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token_2022::instruction::initialize_multisig2(
+        ctx.program.key,
+        ctx.accounts.multisig.key,
+        &signers,
+        m,
+    )?;
+    let mut account_infos = vec![ctx.accounts.multisig];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn mint_to_checked<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, MintToChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token_2022::instruction::mint_to_checked(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.to.key,
+        ctx.accounts.authority.key,
+        &signers,
+        amount,
+        decimals,
+    )?;
+    let mut account_infos = vec![ctx.accounts.mint, ctx.accounts.to, ctx.accounts.authority];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn burn_checked<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, BurnChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = spl_token_2022::instruction::burn_checked(
+        ctx.program.key,
+        ctx.accounts.from.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.authority.key,
+        &signers,
+        amount,
+        decimals,
+    )?;
+    let mut account_infos = vec![ctx.accounts.from, ctx.accounts.mint, ctx.accounts.authority];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn initialize_account2<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, InitializeAccount2<'info>>,
+) -> Result<()> {
+    let ix = spl_token_2022::instruction::initialize_account2(
+        ctx.program.key,
+        ctx.accounts.account.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.authority.key,
+    )?;
+    solana_program::program::invoke_signed(
+        &ix,
+        &[ctx.accounts.account, ctx.accounts.mint, ctx.accounts.rent],
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+pub fn initialize_immutable_owner<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, InitializeImmutableOwner<'info>>,
+) -> Result<()> {
+    let ix = spl_token_2022::instruction::initialize_immutable_owner(
+        ctx.program.key,
+        ctx.accounts.account.key,
+    )?;
+    solana_program::program::invoke_signed(&ix, &[ctx.accounts.account], ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+/// Returns the size in bytes of a token account for the mint.
+pub fn get_account_data_size<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, GetAccountDataSize<'info>>,
+    extension_types: &[spl_token_2022::extension::ExtensionType],
+) -> Result<u64> {
+    let ix = spl_token_2022::instruction::get_account_data_size(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        extension_types,
+    )?;
+    let program_id = ix.program_id;
+    solana_program::program::invoke_signed(&ix, &[ctx.accounts.mint], ctx.signer_seeds)?;
+    let data = return_data(&program_id)?;
+    data.try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|_| ProgramError::InvalidInstructionData.into())
+}
+
+/// Converts a raw `amount` into its UI representation using the mint's decimals.
+pub fn amount_to_ui_amount<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, AmountToUiAmount<'info>>,
+    amount: u64,
+) -> Result<String> {
+    let ix = spl_token_2022::instruction::amount_to_ui_amount(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        amount,
+    )?;
+    let program_id = ix.program_id;
+    solana_program::program::invoke_signed(&ix, &[ctx.accounts.mint], ctx.signer_seeds)?;
+    let data = return_data(&program_id)?;
+    String::from_utf8(data).map_err(|_| ProgramError::InvalidInstructionData.into())
+}
+
+/// Converts a UI amount string into the raw amount using the mint's decimals.
+pub fn ui_amount_to_amount<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, UiAmountToAmount<'info>>,
+    ui_amount: &str,
+) -> Result<u64> {
+    let ix = spl_token_2022::instruction::ui_amount_to_amount(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        ui_amount,
+    )?;
+    let program_id = ix.program_id;
+    solana_program::program::invoke_signed(&ix, &[ctx.accounts.mint], ctx.signer_seeds)?;
+    let data = return_data(&program_id)?;
+    data.try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|_| ProgramError::InvalidInstructionData.into())
+}
+
+#[derive(Accounts)]
+pub struct Transfer<'info> {
+    pub from: AccountInfo<'info>,
+    pub to: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferChecked<'info> {
+    pub from: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub to: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MintTo<'info> {
+    pub mint: AccountInfo<'info>,
+    pub to: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Burn<'info> {
+    pub mint: AccountInfo<'info>,
+    pub from: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Approve<'info> {
+    pub to: AccountInfo<'info>,
+    pub delegate: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveChecked<'info> {
+    pub to: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub delegate: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Revoke<'info> {
+    pub source: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeAccount<'info> {
+    pub account: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeAccount3<'info> {
+    pub account: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseAccount<'info> {
+    pub account: AccountInfo<'info>,
+    pub destination: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeAccount<'info> {
+    pub account: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ThawAccount<'info> {
+    pub account: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeMint<'info> {
+    pub mint: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeMint2<'info> {
+    pub mint: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetAuthority<'info> {
+    pub current_authority: AccountInfo<'info>,
+    pub account_or_mint: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SyncNative<'info> {
+    pub account: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeMultisig<'info> {
+    pub multisig: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeMultisig2<'info> {
+    pub multisig: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MintToChecked<'info> {
+    pub mint: AccountInfo<'info>,
+    pub to: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BurnChecked<'info> {
+    pub mint: AccountInfo<'info>,
+    pub from: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeAccount2<'info> {
+    pub account: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeImmutableOwner<'info> {
+    pub account: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct GetAccountDataSize<'info> {
+    pub mint: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AmountToUiAmount<'info> {
+    pub mint: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UiAmountToAmount<'info> {
+    pub mint: AccountInfo<'info>,
+}
+
+#[derive(Clone)]
+pub struct Token2022;
+
+impl anchor_lang::Id for Token2022 {
+    fn id() -> Pubkey {
+        ID
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stubs;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use spl_token_2022::extension::ExtensionType;
+
+    fn account_info<'a>(key: &'a Pubkey, lamports: &'a mut u64) -> AccountInfo<'a> {
+        AccountInfo::new(key, false, false, lamports, &mut [], key, false, 0)
+    }
+
+    #[test]
+    fn builders_reject_other_programs() {
+        let keys: Vec<_> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let mut lamports = [0; 4];
+        let [program, from, to, authority] = &mut lamports;
+        let ctx = CpiContext::new(
+            account_info(&keys[0], program),
+            Transfer {
+                from: account_info(&keys[1], from),
+                to: account_info(&keys[2], to),
+                authority: account_info(&keys[3], authority),
+            },
+        );
+        assert_eq!(
+            transfer(ctx, 1).unwrap_err(),
+            ProgramError::IncorrectProgramId.into()
+        );
+    }
+
+    #[test]
+    fn get_account_data_size_includes_extensions() {
+        let (mint_key, mut lamports) = (Pubkey::new_unique(), 0);
+        let mut program_lamports = 0;
+        let (size, _) = stubs::invoked(|| {
+            get_account_data_size(
+                CpiContext::new(
+                    account_info(&ID, &mut program_lamports),
+                    GetAccountDataSize {
+                        mint: account_info(&mint_key, &mut lamports),
+                    },
+                ),
+                &[ExtensionType::ImmutableOwner],
+            )
+        });
+        let size = size.unwrap();
+
+        let expected =
+            ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&[
+                ExtensionType::ImmutableOwner,
+            ])
+            .unwrap();
+        assert_eq!(size, expected as u64);
+        assert!(size > spl_token_2022::state::Account::LEN as u64);
+    }
+}
@@ -0,0 +1,293 @@
+use anchor_lang::solana_program::account_info::AccountInfo;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_lang::solana_program::program_error::ProgramError;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_lang::solana_program::sysvar::Sysvar;
+use anchor_lang::{context::CpiContext, Accounts};
+use anchor_lang::{solana_program, Result};
+
+use crate::common::multisig_signers;
+use spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+
+pub use spl_token_2022::extension::transfer_fee;
+
+pub fn initialize_transfer_fee_config<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, InitializeTransferFeeConfig<'info>>,
+    transfer_fee_config_authority: Option<&Pubkey>,
+    withdraw_withheld_authority: Option<&Pubkey>,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> Result<()> {
+    let ix = transfer_fee::instruction::initialize_transfer_fee_config(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        transfer_fee_config_authority,
+        withdraw_withheld_authority,
+        transfer_fee_basis_points,
+        maximum_fee,
+    )?;
+    solana_program::program::invoke_signed(&ix, &[ctx.accounts.mint], ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn transfer_checked_with_fee<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferCheckedWithFee<'info>>,
+    amount: u64,
+    decimals: u8,
+    fee: u64,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = transfer_fee::instruction::transfer_checked_with_fee(
+        ctx.program.key,
+        ctx.accounts.source.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.destination.key,
+        ctx.accounts.authority.key,
+        &signers,
+        amount,
+        decimals,
+        fee,
+    )?;
+    let mut account_infos = vec![
+        ctx.accounts.source,
+        ctx.accounts.mint,
+        ctx.accounts.destination,
+        ctx.accounts.authority,
+    ];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn withdraw_withheld_tokens_from_mint<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, WithdrawWithheldTokensFromMint<'info>>,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = transfer_fee::instruction::withdraw_withheld_tokens_from_mint(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.destination.key,
+        ctx.accounts.authority.key,
+        &signers,
+    )?;
+    let mut account_infos = vec![
+        ctx.accounts.mint,
+        ctx.accounts.destination,
+        ctx.accounts.authority,
+    ];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+/// Withdraws withheld fees from the token accounts passed in
+/// `ctx.remaining_accounts`.
+///
+/// As the remaining accounts already carry the sources, the signers of a
+/// multisig `withdraw_withheld_authority` are passed separately in `signers`,
+/// in the order the multisig expects them. Leave it empty when the authority
+/// signs alone.
+pub fn withdraw_withheld_tokens_from_accounts<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, WithdrawWithheldTokensFromAccounts<'info>>,
+    signers: &[AccountInfo<'info>],
+) -> Result<()> {
+    let sources = ctx
+        .remaining_accounts
+        .iter()
+        .map(|a| a.key)
+        .collect::<Vec<_>>();
+    let ix = transfer_fee::instruction::withdraw_withheld_tokens_from_accounts(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.destination.key,
+        ctx.accounts.authority.key,
+        &multisig_signers(signers),
+        &sources,
+    )?;
+    let mut account_infos = vec![
+        ctx.accounts.mint,
+        ctx.accounts.destination,
+        ctx.accounts.authority,
+    ];
+    account_infos.extend_from_slice(signers);
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+/// Moves withheld fees from the token accounts passed in
+/// `ctx.remaining_accounts` into the mint. Permissionless.
+pub fn harvest_withheld_tokens_to_mint<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, HarvestWithheldTokensToMint<'info>>,
+) -> Result<()> {
+    let sources = ctx
+        .remaining_accounts
+        .iter()
+        .map(|a| a.key)
+        .collect::<Vec<_>>();
+    let ix = transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        &sources,
+    )?;
+    let mut account_infos = vec![ctx.accounts.mint];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+pub fn set_transfer_fee<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, SetTransferFee<'info>>,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> Result<()> {
+    let signers = multisig_signers(&ctx.remaining_accounts);
+    let ix = transfer_fee::instruction::set_transfer_fee(
+        ctx.program.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.authority.key,
+        &signers,
+        transfer_fee_basis_points,
+        maximum_fee,
+    )?;
+    let mut account_infos = vec![ctx.accounts.mint, ctx.accounts.authority];
+    account_infos.extend_from_slice(&ctx.remaining_accounts);
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
+
+/// Returns the fee Token-2022 will withhold when `amount` is transferred
+/// in the current epoch. Mints without a transfer fee config charge nothing.
+pub fn calculate_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let data = mint.try_borrow_data()?;
+    epoch_transfer_fee(&data, || Ok(Clock::get()?.epoch), amount)
+}
+
+// The epoch is only read when the mint has a transfer fee config.
+fn epoch_transfer_fee(
+    mint_data: &[u8],
+    epoch: impl FnOnce() -> Result<u64>,
+    amount: u64,
+) -> Result<u64> {
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(mint_data)?;
+    let config = match state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => config,
+        Err(_) => return Ok(0),
+    };
+    config
+        .calculate_epoch_fee(epoch()?, amount)
+        .ok_or_else(|| ProgramError::ArithmeticOverflow.into())
+}
+
+#[derive(Accounts)]
+pub struct InitializeTransferFeeConfig<'info> {
+    pub mint: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferCheckedWithFee<'info> {
+    pub source: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub destination: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawWithheldTokensFromMint<'info> {
+    pub mint: AccountInfo<'info>,
+    pub destination: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawWithheldTokensFromAccounts<'info> {
+    pub mint: AccountInfo<'info>,
+    pub destination: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct HarvestWithheldTokensToMint<'info> {
+    pub mint: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetTransferFee<'info> {
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program_option::COption;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use spl_token_2022::extension::transfer_fee::TransferFee;
+    use spl_token_2022::extension::{ExtensionType, StateWithExtensionsMut};
+
+    fn mint_state() -> spl_token_2022::state::Mint {
+        spl_token_2022::state::Mint {
+            mint_authority: COption::Some(Pubkey::new_unique()),
+            supply: 1_000_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+    }
+
+    fn transfer_fee(epoch: u64, basis_points: u16, maximum_fee: u64) -> TransferFee {
+        TransferFee {
+            epoch: epoch.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: basis_points.into(),
+        }
+    }
+
+    // A mint charging 1% up to 5_000 until epoch 10, and 2% up to 5_000 from then on.
+    fn mint_with_transfer_fee() -> Vec<u8> {
+        let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+            ExtensionType::TransferFeeConfig,
+        ])
+        .unwrap();
+        let mut data = vec![0u8; len];
+        let mut state =
+            StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(&mut data)
+                .unwrap();
+        let config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+        config.older_transfer_fee = transfer_fee(0, 100, 5_000);
+        config.newer_transfer_fee = transfer_fee(10, 200, 5_000);
+        state.base = mint_state();
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
+    }
+
+    #[test]
+    fn mint_without_transfer_fee_charges_nothing() {
+        let mut data = vec![0u8; spl_token_2022::state::Mint::LEN];
+        mint_state().pack_into_slice(&mut data);
+        let fee = epoch_transfer_fee(&data, || panic!("epoch read without a fee config"), 10_000);
+        assert_eq!(fee.unwrap(), 0);
+    }
+
+    #[test]
+    fn transfer_fee_follows_the_epoch() {
+        let data = mint_with_transfer_fee();
+        assert_eq!(epoch_transfer_fee(&data, || Ok(9), 10_000).unwrap(), 100);
+        assert_eq!(epoch_transfer_fee(&data, || Ok(10), 10_000).unwrap(), 200);
+    }
+
+    #[test]
+    fn transfer_fee_is_capped() {
+        let data = mint_with_transfer_fee();
+        assert_eq!(
+            epoch_transfer_fee(&data, || Ok(10), 250_000).unwrap(),
+            5_000
+        );
+        assert_eq!(
+            epoch_transfer_fee(&data, || Ok(10), u64::MAX).unwrap(),
+            5_000
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use anchor_lang::error::ErrorCode;
+use anchor_lang::solana_program::program_error::ProgramError;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use spl_token_2022::extension::{
+    BaseState, BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+};
+use std::io::Write;
+use std::ops::Deref;
+
+pub use crate::token_2022::*;
+
+static IDS: [Pubkey; 2] = [spl_token::ID, spl_token_2022::ID];
+
+/// Token account owned by either the legacy Token program or Token-2022.
+///
+/// The base state derefs to `spl_token_2022::state::Account`, which shares its
+/// layout with the legacy account. Any TLV extensions present after the base
+/// state are listed by [`TokenAccount::extensions`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TokenAccount {
+    base: spl_token_2022::state::Account,
+    extensions: Vec<ExtensionType>,
+    // Everything stored after the base state, written back as is.
+    tlv_data: Vec<u8>,
+}
+
+impl TokenAccount {
+    /// Creates a token account without extensions.
+    pub fn new(base: spl_token_2022::state::Account) -> Self {
+        Self {
+            base,
+            ..Default::default()
+        }
+    }
+
+    pub fn into_inner(self) -> spl_token_2022::state::Account {
+        self.base
+    }
+
+    /// Extension types present on the account, in TLV order.
+    pub fn extensions(&self) -> &[ExtensionType] {
+        &self.extensions
+    }
+
+    pub fn has_extension(&self, extension: ExtensionType) -> bool {
+        self.extensions.contains(&extension)
+    }
+}
+
+impl anchor_lang::AccountDeserialize for TokenAccount {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let (base, extensions, tlv_data) =
+            unpack_with_extensions::<spl_token_2022::state::Account>(buf)?;
+        Ok(TokenAccount {
+            base,
+            extensions,
+            tlv_data,
+        })
+    }
+}
+
+impl anchor_lang::AccountSerialize for TokenAccount {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
+        pack_with_extensions(&self.base, &self.tlv_data, writer)
+    }
+}
+
+impl From<spl_token_2022::state::Account> for TokenAccount {
+    fn from(base: spl_token_2022::state::Account) -> Self {
+        Self::new(base)
+    }
+}
+
+impl anchor_lang::Owners for TokenAccount {
+    fn owners() -> &'static [Pubkey] {
+        &IDS
+    }
+}
+
+impl Deref for TokenAccount {
+    type Target = spl_token_2022::state::Account;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::IdlBuild for TokenAccount {}
+
+/// Mint owned by either the legacy Token program or Token-2022.
+///
+/// The base state derefs to `spl_token_2022::state::Mint`. Any TLV extensions
+/// present after the base state are listed by [`Mint::extensions`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Mint {
+    base: spl_token_2022::state::Mint,
+    extensions: Vec<ExtensionType>,
+    // Everything stored after the base state, written back as is.
+    tlv_data: Vec<u8>,
+}
+
+impl Mint {
+    /// Creates a mint without extensions.
+    pub fn new(base: spl_token_2022::state::Mint) -> Self {
+        Self {
+            base,
+            ..Default::default()
+        }
+    }
+
+    pub fn into_inner(self) -> spl_token_2022::state::Mint {
+        self.base
+    }
+
+    /// Extension types present on the mint, in TLV order.
+    pub fn extensions(&self) -> &[ExtensionType] {
+        &self.extensions
+    }
+
+    pub fn has_extension(&self, extension: ExtensionType) -> bool {
+        self.extensions.contains(&extension)
+    }
+}
+
+impl anchor_lang::AccountDeserialize for Mint {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let (base, extensions, tlv_data) =
+            unpack_with_extensions::<spl_token_2022::state::Mint>(buf)?;
+        Ok(Mint {
+            base,
+            extensions,
+            tlv_data,
+        })
+    }
+}
+
+impl anchor_lang::AccountSerialize for Mint {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
+        pack_with_extensions(&self.base, &self.tlv_data, writer)
+    }
+}
+
+impl From<spl_token_2022::state::Mint> for Mint {
+    fn from(base: spl_token_2022::state::Mint) -> Self {
+        Self::new(base)
+    }
+}
+
+impl anchor_lang::Owners for Mint {
+    fn owners() -> &'static [Pubkey] {
+        &IDS
+    }
+}
+
+impl Deref for Mint {
+    type Target = spl_token_2022::state::Mint;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+#[cfg(feature = "idl-build")]
+impl anchor_lang::IdlBuild for Mint {}
+
+#[derive(Clone)]
+pub struct TokenInterface;
+
+impl anchor_lang::Ids for TokenInterface {
+    fn ids() -> &'static [Pubkey] {
+        &IDS
+    }
+}
+
+// Legacy accounts are exactly `S::LEN` bytes long and carry no TLV area, so
+// they come back with an empty extension list. For Token-2022 accounts the
+// bytes after the base state (padding, account type and TLV entries) are kept
+// so the account serializes back to the same data.
+fn unpack_with_extensions<S: BaseState>(
+    buf: &[u8],
+) -> Result<(S, Vec<ExtensionType>, Vec<u8>), ProgramError> {
+    let state = StateWithExtensions::<S>::unpack(buf)?;
+    let extensions = state.get_extension_types()?;
+    Ok((state.base, extensions, buf[S::LEN..].to_vec()))
+}
+
+fn pack_with_extensions<S: BaseState, W: Write>(
+    base: &S,
+    tlv_data: &[u8],
+    writer: &mut W,
+) -> anchor_lang::Result<()> {
+    let mut data = vec![0u8; S::LEN];
+    base.pack_into_slice(&mut data);
+    data.extend_from_slice(tlv_data);
+    writer
+        .write_all(&data)
+        .map_err(|_| ErrorCode::AccountDidNotSerialize.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program_option::COption;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_lang::{AccountDeserialize, AccountSerialize};
+    use spl_token_2022::extension::mint_close_authority::MintCloseAuthority;
+    use spl_token_2022::extension::{ExtensionType, StateWithExtensionsMut};
+    use spl_token_2022::state::AccountState;
+
+    fn mint_state() -> spl_token_2022::state::Mint {
+        spl_token_2022::state::Mint {
+            mint_authority: COption::Some(Pubkey::new_unique()),
+            supply: 1_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+    }
+
+    fn round_trip<T: AccountSerialize + AccountDeserialize>(account: &T) -> (Vec<u8>, T) {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        let back = T::try_deserialize(&mut data.as_slice()).unwrap();
+        (data, back)
+    }
+
+    #[test]
+    fn legacy_token_account_round_trips() {
+        let account = TokenAccount::new(spl_token_2022::state::Account {
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            amount: 42,
+            state: AccountState::Initialized,
+            ..Default::default()
+        });
+        let (data, back) = round_trip(&account);
+        assert_eq!(data.len(), spl_token_2022::state::Account::LEN);
+        assert_eq!(back, account);
+        assert!(back.extensions().is_empty());
+    }
+
+    #[test]
+    fn legacy_mint_round_trips() {
+        let mint = Mint::new(mint_state());
+        let (data, back) = round_trip(&mint);
+        assert_eq!(data.len(), spl_token_2022::state::Mint::LEN);
+        assert_eq!(back, mint);
+    }
+
+    #[test]
+    fn mint_with_extension_round_trips() {
+        let len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+            ExtensionType::MintCloseAuthority,
+        ])
+        .unwrap();
+        let base = mint_state();
+        let mut data = vec![0u8; len];
+        let mut state =
+            StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(&mut data)
+                .unwrap();
+        state
+            .init_extension::<MintCloseAuthority>(true)
+            .unwrap()
+            .close_authority = Some(Pubkey::new_unique()).try_into().unwrap();
+        state.base = base;
+        state.pack_base();
+        state.init_account_type().unwrap();
+
+        let mint = Mint::try_deserialize(&mut data.as_slice()).unwrap();
+        assert!(mint.has_extension(ExtensionType::MintCloseAuthority));
+        assert_eq!(*mint, base);
+
+        let mut serialized = Vec::new();
+        mint.try_serialize(&mut serialized).unwrap();
+        assert_eq!(serialized, data);
+    }
+}